@@ -0,0 +1,132 @@
+use {
+	super::{Health, KzBackend, Map, Mapper, Player, Record},
+	crate::{dawn_api, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype},
+};
+
+impl From<dawn_api::Map> for Map {
+	fn from(map: dawn_api::Map) -> Self {
+		Self {
+			tier: map.tier(),
+			bonuses: Some(map.courses.len().saturating_sub(1) as u8),
+			id: map.id,
+			name: map.name,
+			global: map.global,
+			workshop_id: map.workshop_id,
+			mappers: map
+				.mappers
+				.into_iter()
+				.map(|mapper| Mapper { name: mapper.name, steam_id: mapper.id })
+				.collect(),
+			created_on: map.created_on,
+		}
+	}
+}
+
+impl From<dawn_api::Record> for Record {
+	fn from(record: dawn_api::Record) -> Self {
+		Self {
+			id: record.id,
+			map_id: record.map_id,
+			map_name: record.map_name,
+			stage: record.map_stage,
+			mode: record.mode,
+			steam_id: record.steam_id,
+			player_name: record.player_name,
+			time: record.time,
+			teleports: record.teleports,
+			server_id: record.server_id,
+			server_name: record.server_name,
+			points: None,
+			replay_id: None,
+			created_on: record.created_on,
+		}
+	}
+}
+
+impl From<dawn_api::Player> for Player {
+	fn from(player: dawn_api::Player) -> Self {
+		Self {
+			steam_id: player.steam_id,
+			name: player.name,
+			is_banned: Some(player.is_banned),
+			avatar_url: None,
+			country: None,
+		}
+	}
+}
+
+impl From<dawn_api::PlayerWithCompletion> for Player {
+	fn from(player: dawn_api::PlayerWithCompletion) -> Self {
+		Self {
+			steam_id: player.steam_id,
+			name: player.name,
+			is_banned: Some(player.is_banned),
+			avatar_url: None,
+			country: None,
+		}
+	}
+}
+
+impl KzBackend for dawn_api::DawnApi {
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		self.get_map(map).await.map(Into::into)
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		let maps = self.get_maps(true).await?;
+
+		Ok(maps.into_iter().map(Into::into).collect())
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		self.get_wr(map, course, mode, runtype)
+			.await
+			.map(Into::into)
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		self.get_pb(player, map, course, mode, runtype)
+			.await
+			.map(Into::into)
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		let records = self
+			.get_maptop(map, course, mode, runtype, n)
+			.await?;
+
+		Ok(records.into_iter().map(Into::into).collect())
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		self.get_player(player)
+			.await
+			.map(Into::into)
+	}
+
+	async fn healthcheck(&self) -> Result<Health> {
+		let online = self.healthcheck().await?;
+
+		Ok(if online { Health::HEALTHY } else { Health::DOWN })
+	}
+}
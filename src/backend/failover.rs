@@ -0,0 +1,371 @@
+//! Automatic failover between APIs based on their health.
+//!
+//! A [`Failover`] wraps a list of backends in order of preference. Calling
+//! [`Failover::check_health`] (or letting [`Failover::spawn_health_checks`] do it periodically)
+//! marks every backend whose [`Health`] falls below the configured [`Thresholds`] as degraded.
+//! Queries go to the first healthy backend and only reach degraded ones if every healthy backend
+//! failed. A healthy backend reporting that the requested data does not exist (see
+//! [`Error::is_not_found`]) is trusted and ends the query.
+//!
+//! ```no_run
+//! use {
+//!     gokz_rs::backend::{AnyBackend, Failover, KzBackend},
+//!     std::{sync::Arc, time::Duration},
+//! };
+//!
+//! async fn run(primary: AnyBackend, secondary: AnyBackend) -> gokz_rs::Result<()> {
+//!     let failover = Arc::new(Failover::new([primary, secondary]));
+//!
+//!     Arc::clone(&failover).spawn_health_checks(Duration::from_secs(60));
+//!
+//!     let map = failover.get_map("kz_lionharder".into()).await?;
+//!     println!("{} is tier {}", map.name, map.tier);
+//!     Ok(())
+//! }
+//! ```
+
+use {
+	super::{Health, KzBackend, Map, Player, Record},
+	crate::{yeet, Error, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype},
+	futures::future,
+	std::{
+		sync::{
+			atomic::{AtomicBool, Ordering},
+			Arc,
+		},
+		time::Duration,
+	},
+};
+
+#[cfg(feature = "global-api")]
+use crate::global_api::GlobalApi;
+
+#[cfg(feature = "kzgo-api")]
+use crate::kzgo_api::KzgoApi;
+
+#[cfg(feature = "dawn-api")]
+use crate::dawn_api::DawnApi;
+
+/// Any of the supported API clients.
+///
+/// This allows a single [`Failover`] to mix different APIs.
+#[allow(missing_docs)]
+#[derive(Debug, Clone)]
+pub enum AnyBackend {
+	#[cfg(feature = "global-api")]
+	GlobalApi(GlobalApi),
+
+	#[cfg(feature = "kzgo-api")]
+	KzgoApi(KzgoApi),
+
+	#[cfg(feature = "dawn-api")]
+	DawnApi(DawnApi),
+}
+
+#[cfg(feature = "global-api")]
+impl From<GlobalApi> for AnyBackend {
+	fn from(global_api: GlobalApi) -> Self {
+		Self::GlobalApi(global_api)
+	}
+}
+
+#[cfg(feature = "kzgo-api")]
+impl From<KzgoApi> for AnyBackend {
+	fn from(kzgo_api: KzgoApi) -> Self {
+		Self::KzgoApi(kzgo_api)
+	}
+}
+
+#[cfg(feature = "dawn-api")]
+impl From<DawnApi> for AnyBackend {
+	fn from(dawn_api: DawnApi) -> Self {
+		Self::DawnApi(dawn_api)
+	}
+}
+
+macro_rules! dispatch {
+	($self:ident.$method:ident($($arg:expr),*)) => {
+		match $self {
+			#[cfg(feature = "global-api")]
+			Self::GlobalApi(global_api) => KzBackend::$method(global_api, $($arg),*).await,
+
+			#[cfg(feature = "kzgo-api")]
+			Self::KzgoApi(kzgo_api) => KzBackend::$method(kzgo_api, $($arg),*).await,
+
+			#[cfg(feature = "dawn-api")]
+			Self::DawnApi(dawn_api) => KzBackend::$method(dawn_api, $($arg),*).await,
+		}
+	};
+}
+
+impl KzBackend for AnyBackend {
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		dispatch!(self.get_map(map))
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		dispatch!(self.get_maps())
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		dispatch!(self.get_wr(map, course, mode, runtype))
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		dispatch!(self.get_pb(player, map, course, mode, runtype))
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		dispatch!(self.get_maptop(map, course, mode, runtype, n))
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		dispatch!(self.get_player(player))
+	}
+
+	async fn healthcheck(&self) -> Result<Health> {
+		dispatch!(self.healthcheck())
+	}
+}
+
+/// The minimum [`Health`] a backend needs to not be considered degraded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Thresholds {
+	/// The minimum amount of successful healthchecks (out of 10)
+	pub min_successful: u8,
+
+	/// The minimum amount of fast responses (out of 10)
+	pub min_fast: u8,
+}
+
+impl Thresholds {
+	/// Whether `health` meets these thresholds.
+	pub const fn is_met_by(&self, health: Health) -> bool {
+		health.successful >= self.min_successful && health.fast >= self.min_fast
+	}
+}
+
+impl Default for Thresholds {
+	fn default() -> Self {
+		Self { min_successful: 7, min_fast: 5 }
+	}
+}
+
+#[derive(Debug)]
+struct Entry<B> {
+	backend: B,
+	degraded: AtomicBool,
+}
+
+/// A [`KzBackend`] which routes every query to the first healthy backend.
+///
+/// Backends start out healthy and are only marked as degraded by [`Failover::check_health`].
+///
+/// If every backend fails, the error of the last backend that supports the operation is returned;
+/// [`Error::Unsupported`] is only returned if no backend supports it.
+#[derive(Debug)]
+pub struct Failover<B = AnyBackend> {
+	backends: Vec<Entry<B>>,
+	thresholds: Thresholds,
+}
+
+impl<B> Failover<B> {
+	/// Constructs a new [`Failover`] over `backends`, in order of preference.
+	pub fn new(backends: impl IntoIterator<Item = B>) -> Self {
+		Self {
+			backends: backends
+				.into_iter()
+				.map(|backend| Entry { backend, degraded: AtomicBool::new(false) })
+				.collect(),
+			thresholds: Thresholds::default(),
+		}
+	}
+
+	/// Sets the [`Thresholds`] below which a backend is considered degraded.
+	pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+		self.thresholds = thresholds;
+		self
+	}
+
+	/// The backends, in order of preference.
+	pub fn backends(&self) -> impl Iterator<Item = &B> {
+		self.backends
+			.iter()
+			.map(|entry| &entry.backend)
+	}
+
+	/// Whether the backend at `idx` was marked as degraded by the last health check.
+	///
+	/// Returns `None` if there is no backend at `idx`.
+	pub fn is_degraded(&self, idx: usize) -> Option<bool> {
+		self.backends
+			.get(idx)
+			.map(|entry| entry.degraded.load(Ordering::Relaxed))
+	}
+
+	/// Healthy backends first, then degraded ones, each in order of preference.
+	fn by_health(&self) -> impl Iterator<Item = (usize, &B)> {
+		let (healthy, degraded) = self
+			.backends
+			.iter()
+			.enumerate()
+			.partition::<Vec<_>, _>(|(_, entry)| !entry.degraded.load(Ordering::Relaxed));
+
+		healthy
+			.into_iter()
+			.chain(degraded)
+			.map(|(idx, entry)| (idx, &entry.backend))
+	}
+}
+
+impl<B: KzBackend + Sync> Failover<B> {
+	/// Checks the health of every backend and updates which ones are degraded.
+	///
+	/// Backends whose health check fails entirely are considered degraded as well.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn check_health(&self) {
+		let checks = self
+			.backends
+			.iter()
+			.map(|entry| entry.backend.healthcheck());
+
+		let results = future::join_all(checks).await;
+
+		for (idx, (entry, result)) in self.backends.iter().zip(results).enumerate() {
+			let degraded = match &result {
+				Ok(health) => !self.thresholds.is_met_by(*health),
+				Err(_) => true,
+			};
+
+			if entry.degraded.swap(degraded, Ordering::Relaxed) == degraded {
+				continue;
+			}
+
+			match (degraded, result) {
+				(true, Ok(health)) => tracing::warn!(backend = idx, ?health, "backend degraded"),
+				(true, Err(error)) => tracing::warn!(backend = idx, ?error, "backend degraded"),
+				(false, _) => tracing::info!(backend = idx, "backend recovered"),
+			}
+		}
+	}
+
+	/// Spawns a task which calls [`Failover::check_health`] every `interval`, starting
+	/// immediately.
+	///
+	/// The task runs until it is aborted.
+	pub fn spawn_health_checks(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()>
+	where
+		B: Send + 'static,
+	{
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(interval);
+
+			loop {
+				interval.tick().await;
+				self.check_health().await;
+			}
+		})
+	}
+}
+
+macro_rules! failover {
+	($self:ident.$method:ident($($arg:expr),*)) => {{
+		let mut last_error = None;
+
+		for (idx, backend) in $self.by_health() {
+			match backend.$method($($arg.clone()),*).await {
+				Ok(value) => return Ok(value),
+				Err(error) if error.is_not_found() && $self.is_degraded(idx) == Some(false) => {
+					return Err(error);
+				}
+				Err(error) => {
+					tracing::debug!(
+						backend = idx,
+						?error,
+						concat!("`", stringify!($method), "` failed; trying next backend")
+					);
+
+					if last_error.is_none() || !matches!(error, Error::Unsupported(_)) {
+						last_error = Some(error);
+					}
+				}
+			}
+		}
+
+		match last_error {
+			Some(error) => Err(error),
+			None => yeet!(Custom("No backends configured.")),
+		}
+	}};
+}
+
+impl<B: KzBackend + Sync> KzBackend for Failover<B> {
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		failover!(self.get_map(map))
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		failover!(self.get_maps())
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		failover!(self.get_wr(map, course, mode, runtype))
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		failover!(self.get_pb(player, map, course, mode, runtype))
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		failover!(self.get_maptop(map, course, mode, runtype, n))
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		failover!(self.get_player(player))
+	}
+
+	/// Returns the health of the first backend that responds, preferring healthy ones.
+	async fn healthcheck(&self) -> Result<Health> {
+		failover!(self.healthcheck())
+	}
+}
@@ -0,0 +1,122 @@
+use {
+	super::{Health, KzBackend, Map, Player, Record},
+	crate::{global_api, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype},
+};
+
+impl From<global_api::Map> for Map {
+	fn from(map: global_api::Map) -> Self {
+		Self {
+			workshop_id: map.workshop_id(),
+			id: map.id,
+			name: map.name,
+			tier: map.difficulty,
+			global: map.validated,
+			bonuses: None,
+			mappers: Vec::new(),
+			created_on: map.created_on,
+		}
+	}
+}
+
+impl From<global_api::Record> for Record {
+	fn from(record: global_api::Record) -> Self {
+		Self {
+			id: record.id,
+			map_id: record.map_id,
+			map_name: record.map_name,
+			stage: record.stage,
+			mode: record.mode,
+			steam_id: record.steam_id,
+			player_name: record.player_name,
+			time: record.time,
+			teleports: record.teleports,
+			server_id: record.server_id,
+			server_name: record.server_name,
+			points: Some(record.points),
+			replay_id: (record.replay_id != 0).then_some(record.replay_id),
+			created_on: record.created_on,
+		}
+	}
+}
+
+impl From<global_api::Player> for Player {
+	fn from(player: global_api::Player) -> Self {
+		Self {
+			steam_id: player.steam_id,
+			name: player.name,
+			is_banned: Some(player.is_banned),
+			avatar_url: None,
+			country: None,
+		}
+	}
+}
+
+impl From<global_api::Health> for Health {
+	fn from(global_api::Health { successful, fast }: global_api::Health) -> Self {
+		Self { successful, fast }
+	}
+}
+
+impl KzBackend for global_api::GlobalApi {
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		self.get_map(&map.to_string())
+			.await
+			.map(Into::into)
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		let maps = self.get_maps(true).await?;
+
+		Ok(maps.into_iter().map(Into::into).collect())
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		self.get_wr(map, course, mode, runtype)
+			.await
+			.map(Into::into)
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		self.get_pb(player, map, course, mode, runtype)
+			.await
+			.map(Into::into)
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		let records = self
+			.get_maptop(map, course, mode, runtype, n)
+			.await?;
+
+		Ok(records.into_iter().map(Into::into).collect())
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		self.get_player(player)
+			.await
+			.map(Into::into)
+	}
+
+	async fn healthcheck(&self) -> Result<Health> {
+		self.healthcheck().await.map(Into::into)
+	}
+}
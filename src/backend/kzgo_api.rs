@@ -0,0 +1,103 @@
+//! KZ:GO only knows about global maps and Steam profiles; everything else is
+//! [`Error::Unsupported`](crate::Error::Unsupported).
+//!
+//! KZ:GO has no health endpoint, so health checks fetch the map list instead.
+
+use {
+	super::{Health, KzBackend, Map, Mapper, Player, Record},
+	crate::{kzgo_api, yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype},
+};
+
+impl From<kzgo_api::Map> for Map {
+	fn from(map: kzgo_api::Map) -> Self {
+		Self {
+			id: map.id,
+			name: map.name,
+			tier: map.tier,
+			global: true,
+			bonuses: Some(map.bonuses),
+			workshop_id: map.workshop_id,
+			mappers: map
+				.mapper_names
+				.into_iter()
+				.zip(map.mapper_ids)
+				.map(|(name, steam_id)| Mapper { name, steam_id })
+				.collect(),
+			created_on: map.date,
+		}
+	}
+}
+
+impl KzBackend for kzgo_api::KzgoApi {
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		match map {
+			MapIdentifier::Name(name) => self.get_map(&name).await.map(Into::into),
+			MapIdentifier::ID(id) => {
+				let Some(map) = self
+					.get_maps()
+					.await?
+					.into_iter()
+					.find(|map| map.id == id)
+				else {
+					yeet!(EmptyResponse);
+				};
+
+				Ok(map.into())
+			}
+		}
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		let maps = self.get_maps().await?;
+
+		Ok(maps.into_iter().map(Into::into).collect())
+	}
+
+	async fn get_wr(&self, _: MapIdentifier, _: u8, _: Mode, _: Runtype) -> Result<Record> {
+		yeet!(Unsupported("`get_wr`"));
+	}
+
+	async fn get_pb(
+		&self,
+		_: PlayerIdentifier,
+		_: MapIdentifier,
+		_: u8,
+		_: Mode,
+		_: Runtype,
+	) -> Result<Record> {
+		yeet!(Unsupported("`get_pb`"));
+	}
+
+	async fn get_maptop(
+		&self,
+		_: MapIdentifier,
+		_: u8,
+		_: Mode,
+		_: Runtype,
+		_: usize,
+	) -> Result<Vec<Record>> {
+		yeet!(Unsupported("`get_maptop`"));
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		let PlayerIdentifier::SteamID(steam_id) = player else {
+			yeet!(Unsupported("Looking up players by name"));
+		};
+
+		let user = self.get_user(steam_id).await?;
+
+		Ok(Player {
+			steam_id,
+			name: user.name,
+			is_banned: None,
+			avatar_url: Some(user.avatar_url),
+			country: Some(user.country),
+		})
+	}
+
+	async fn healthcheck(&self) -> Result<Health> {
+		self.get_maps().await?;
+
+		Ok(Health::HEALTHY)
+	}
+}
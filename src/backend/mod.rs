@@ -0,0 +1,372 @@
+//! This module contains a common interface over all supported APIs.
+//!
+//! The GlobalAPI, KZ:GO and DawnAPI all describe the same concepts (maps, records and players)
+//! with slightly different types. [`KzBackend`] exposes the operations they have in common and
+//! returns normalized [`Map`], [`Record`] and [`Player`] types, so applications can swap between
+//! APIs or use [`Fallback`] to try another API if one is unavailable. [`Failover`] goes one step
+//! further and routes queries based on each API's health.
+//!
+//! Operations an API does not support return [`Error::Unsupported`](crate::Error::Unsupported).
+//!
+//! ```no_run
+//! use gokz_rs::{backend::KzBackend, Mode, Runtype};
+//!
+//! async fn print_wr(backend: &impl KzBackend) -> gokz_rs::Result<()> {
+//!     let wr = backend
+//!         .get_wr("kz_lionharder".into(), 0, Mode::SimpleKZ, Runtype::Pro)
+//!         .await?;
+//!
+//!     println!("{} by {}", wr.time, wr.player_name);
+//!     Ok(())
+//! }
+//! ```
+
+use {
+	crate::{Error, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, SteamID, Tier},
+	serde::{Deserialize, Serialize},
+	std::future::Future,
+};
+
+#[cfg(feature = "global-api")]
+mod global_api;
+
+#[cfg(feature = "kzgo-api")]
+mod kzgo_api;
+
+#[cfg(feature = "dawn-api")]
+mod dawn_api;
+
+pub mod failover;
+pub use failover::{AnyBackend, Failover};
+
+/// Operations supported by all APIs.
+///
+/// The methods mirror the ones on the API clients with the same names.
+pub trait KzBackend {
+	/// Fetches a single map.
+	fn get_map(&self, map: MapIdentifier) -> impl Future<Output = Result<Map>> + Send;
+
+	/// Fetches all global maps.
+	fn get_maps(&self) -> impl Future<Output = Result<Vec<Map>>> + Send;
+
+	/// Fetches the world record on the given course.
+	fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> impl Future<Output = Result<Record>> + Send;
+
+	/// Fetches a player's personal best on the given course.
+	fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> impl Future<Output = Result<Record>> + Send;
+
+	/// Fetches the top `n` records on the given course.
+	fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> impl Future<Output = Result<Vec<Record>>> + Send;
+
+	/// Fetches a single player.
+	fn get_player(&self, player: PlayerIdentifier) -> impl Future<Output = Result<Player>> + Send;
+
+	/// Checks whether the API is currently available.
+	fn healthcheck(&self) -> impl Future<Output = Result<Health>> + Send;
+}
+
+impl<T: KzBackend + Sync> KzBackend for &T {
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		T::get_map(self, map).await
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		T::get_maps(self).await
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		T::get_wr(self, map, course, mode, runtype).await
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		T::get_pb(self, player, map, course, mode, runtype).await
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		T::get_maptop(self, map, course, mode, runtype, n).await
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		T::get_player(self, player).await
+	}
+
+	async fn healthcheck(&self) -> Result<Health> {
+		T::healthcheck(self).await
+	}
+}
+
+/// A summary of an API's recent health checks, see [`KzBackend::healthcheck`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Health {
+	/// The amount of successful healthchecks (out of 10)
+	pub successful: u8,
+
+	/// The amount of fast responses (out of 10)
+	pub fast: u8,
+}
+
+impl Health {
+	/// All recent health checks succeeded quickly.
+	pub const HEALTHY: Self = Self { successful: 10, fast: 10 };
+
+	/// All recent health checks failed.
+	pub const DOWN: Self = Self { successful: 0, fast: 0 };
+}
+
+/// A map, as returned by any [`KzBackend`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Map {
+	#[allow(missing_docs)]
+	pub id: u16,
+
+	#[allow(missing_docs)]
+	pub name: String,
+
+	/// The tier of the main course.
+	pub tier: Tier,
+
+	/// Whether the map is global.
+	pub global: bool,
+
+	/// The amount of bonuses, if the API knows about them.
+	pub bonuses: Option<u8>,
+
+	#[allow(missing_docs)]
+	pub workshop_id: Option<u32>,
+
+	/// The people who made the map, if the API knows about them.
+	pub mappers: Vec<Mapper>,
+
+	#[cfg(feature = "chrono")]
+	#[serde(
+		serialize_with = "crate::serde::chrono::serialize_date",
+		deserialize_with = "crate::serde::chrono::deserialize_date"
+	)]
+	#[allow(missing_docs)]
+	pub created_on: chrono::DateTime<chrono::Utc>,
+
+	#[cfg(not(feature = "chrono"))]
+	#[allow(missing_docs)]
+	pub created_on: String,
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Mapper {
+	pub name: String,
+	pub steam_id: SteamID,
+}
+
+/// A record, as returned by any [`KzBackend`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+	#[allow(missing_docs)]
+	pub id: u32,
+
+	#[allow(missing_docs)]
+	pub map_id: u16,
+
+	#[allow(missing_docs)]
+	pub map_name: String,
+
+	#[allow(missing_docs)]
+	pub stage: u8,
+
+	#[allow(missing_docs)]
+	pub mode: Mode,
+
+	#[allow(missing_docs)]
+	pub steam_id: SteamID,
+
+	#[allow(missing_docs)]
+	pub player_name: String,
+
+	#[allow(missing_docs)]
+	pub time: f64,
+
+	#[allow(missing_docs)]
+	pub teleports: u32,
+
+	#[allow(missing_docs)]
+	pub server_id: u16,
+
+	#[allow(missing_docs)]
+	pub server_name: String,
+
+	/// The points for this record, if the API knows about them.
+	pub points: Option<u32>,
+
+	/// The id of this record's replay, if it has one and the API knows about it.
+	pub replay_id: Option<u32>,
+
+	#[cfg(feature = "chrono")]
+	#[serde(
+		serialize_with = "crate::serde::chrono::serialize_date",
+		deserialize_with = "crate::serde::chrono::deserialize_date"
+	)]
+	#[allow(missing_docs)]
+	pub created_on: chrono::DateTime<chrono::Utc>,
+
+	#[cfg(not(feature = "chrono"))]
+	#[allow(missing_docs)]
+	pub created_on: String,
+}
+
+impl Record {
+	/// Returns the [`Runtype`] for this record.
+	pub const fn runtype(&self) -> Runtype {
+		match self.teleports > 0 {
+			true => Runtype::TP,
+			false => Runtype::Pro,
+		}
+	}
+}
+
+/// A player, as returned by any [`KzBackend`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Player {
+	#[allow(missing_docs)]
+	pub steam_id: SteamID,
+
+	#[allow(missing_docs)]
+	pub name: String,
+
+	/// Whether the player is banned, if the API knows about it.
+	pub is_banned: Option<bool>,
+
+	/// The player's Steam avatar, if the API knows about it.
+	pub avatar_url: Option<String>,
+
+	/// The player's country, if the API knows about it.
+	pub country: Option<String>,
+}
+
+/// A [`KzBackend`] which tries `primary` first and only uses `secondary` if that fails.
+///
+/// If `primary` reports that the requested data does not exist (see [`Error::is_not_found`]),
+/// that error is returned as is. If both fail, the error from `secondary` is returned, unless
+/// `secondary` does not support the operation at all ([`Error::Unsupported`]).
+#[allow(missing_docs)]
+#[derive(Debug, Clone)]
+pub struct Fallback<A, B> {
+	pub primary: A,
+	pub secondary: B,
+}
+
+impl<A, B> Fallback<A, B> {
+	/// Constructs a new [`Fallback`].
+	pub const fn new(primary: A, secondary: B) -> Self {
+		Self { primary, secondary }
+	}
+}
+
+macro_rules! fallback {
+	($self:ident.$method:ident($($arg:expr),*)) => {
+		match $self.primary.$method($($arg.clone()),*).await {
+			Ok(value) => Ok(value),
+			Err(error) if error.is_not_found() => Err(error),
+			Err(error) => {
+				tracing::debug!(?error, concat!("`", stringify!($method), "` failed; falling back"));
+
+				match $self.secondary.$method($($arg),*).await {
+					Err(Error::Unsupported(_)) => Err(error),
+					result => result,
+				}
+			}
+		}
+	};
+}
+
+impl<A, B> KzBackend for Fallback<A, B>
+where
+	A: KzBackend + Sync,
+	B: KzBackend + Sync,
+{
+	async fn get_map(&self, map: MapIdentifier) -> Result<Map> {
+		fallback!(self.get_map(map))
+	}
+
+	async fn get_maps(&self) -> Result<Vec<Map>> {
+		fallback!(self.get_maps())
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		fallback!(self.get_wr(map, course, mode, runtype))
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> Result<Record> {
+		fallback!(self.get_pb(player, map, course, mode, runtype))
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		fallback!(self.get_maptop(map, course, mode, runtype, n))
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> Result<Player> {
+		fallback!(self.get_player(player))
+	}
+
+	async fn healthcheck(&self) -> Result<Health> {
+		fallback!(self.healthcheck())
+	}
+}
@@ -0,0 +1,300 @@
+//! Blocking version of [`crate::dawn_api`].
+
+use crate::{
+	dawn_api::{
+		maps, players,
+		players::{CompletedCourse, CompletionParams, ProgressionParams, ProgressionRecord},
+		records, servers, Map, Player, PlayerWithCompletion, Record, Server,
+	},
+	http::{self, Pagination},
+	yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, ServerIdentifier,
+};
+
+super::blocking_client! {
+	/// Blocking version of [`crate::dawn_api::DawnApi`].
+	DawnApi, DawnApiBuilder, crate::dawn_api::API_URL, crate::dawn_api::health::URL
+}
+
+/// Query parameters for endpoints which don't take any.
+const NO_PARAMS: &[(&str, &str)] = &[];
+
+impl DawnApi {
+	/// Blocking version of [`crate::dawn_api::DawnApi::healthcheck`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn healthcheck(&self) -> Result<bool> {
+		let url = self
+			.health_url()
+			.expect("DawnAPI always has a health URL.");
+
+		Ok(self.get_response(url, &NO_PARAMS)?.text()? == "balls")
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_maps_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maps_with(&self, params: &maps::Params) -> Result<Vec<Map>> {
+		self.get_list(format!("{}/maps", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::stream_maps_with`].
+	pub fn iter_maps_with<'a>(
+		&'a self,
+		params: &maps::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Map>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_maps_with(&maps::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_maps`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maps(&self, global: bool) -> Result<Vec<Map>> {
+		let params = maps::Params { global: Some(global), ..Default::default() };
+		let maps = self
+			.iter_maps_with(&params, Pagination::new().page_size(1000))
+			.collect::<Result<Vec<_>>>()?;
+
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(maps)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_map`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_map(&self, map: impl Into<MapIdentifier> + std::fmt::Debug) -> Result<Map> {
+		self.get(format!("{}/maps/{}", self.base_url(), map.into()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_maps_by`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maps_by(
+		&self,
+		mapper: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<Vec<Map>> {
+		let params = maps::Params { mapper: Some(mapper.into()), ..Default::default() };
+
+		self.get_maps_with(&params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_players_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_players_with(&self, params: &players::Params) -> Result<Vec<Player>> {
+		self.get_list(format!("{}/players", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::stream_players_with`].
+	pub fn iter_players_with<'a>(
+		&'a self,
+		params: &players::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Player>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_players_with(&players::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_player`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_player(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<PlayerWithCompletion> {
+		self.get(format!("{}/players/{}", self.base_url(), player.into()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_completed`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_completed(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		params: &CompletionParams,
+	) -> Result<Vec<CompletedCourse>> {
+		self.get_list(params.url(self.base_url(), player.into()), params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_pb_progresion`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_pb_progresion(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		params: &ProgressionParams,
+	) -> Result<Vec<ProgressionRecord>> {
+		let url = format!(
+			"{}/players/{}/progression/{}/{}",
+			self.base_url(),
+			player.into(),
+			map.into(),
+			mode.into().api()
+		);
+
+		self.get_list(url, params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_servers_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_servers_with(&self, params: &servers::Params) -> Result<Vec<Server>> {
+		self.get_list(format!("{}/servers", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::stream_servers_with`].
+	pub fn iter_servers_with<'a>(
+		&'a self,
+		params: &servers::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Server>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_servers_with(&servers::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_servers_owned_by`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_servers_owned_by(
+		&self,
+		server_owner: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<Vec<Server>> {
+		let params = servers::Params { owner: Some(server_owner.into()), ..Default::default() };
+
+		self.get_servers_with(&params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_server`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_server(
+		&self,
+		server: impl Into<ServerIdentifier> + std::fmt::Debug,
+	) -> Result<Server> {
+		self.get(format!("{}/servers/{}", self.base_url(), server.into()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_record`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_record(&self, record_id: u32) -> Result<Record> {
+		self.get(format!("{}/records/{record_id}", self.base_url()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_records_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_records_with(&self, params: &records::Params) -> Result<Vec<Record>> {
+		self.get_list(format!("{}/records", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::stream_records_with`].
+	pub fn iter_records_with<'a>(
+		&'a self,
+		params: &records::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Record>> + 'a {
+		let params = params.clone();
+		let offset = params
+			.offset
+			.and_then(|offset| u32::try_from(offset).ok())
+			.unwrap_or(0);
+
+		http::blocking::paginate(offset, pagination, move |offset, limit| {
+			self.get_records_with(&records::Params {
+				offset: Some(offset as i64),
+				limit: Some(limit as u64),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_wr`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_wr(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		stage: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		Ok(self.get_maptop(map, stage, mode, runtype, 1)?.remove(0))
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_maptop`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maptop(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		stage: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		let params = records::Params {
+			map: Some(map.into()),
+			stage: Some(stage),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(n as u64),
+			..Default::default()
+		};
+
+		self.get_list(format!("{}/records/top", self.base_url()), &params)
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_pb`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_pb(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		let params = records::Params {
+			map: Some(map.into()),
+			stage: Some(course),
+			player: Some(player.into()),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(1),
+			..Default::default()
+		};
+
+		Ok(self
+			.get_list(format!("{}/records/top", self.base_url()), &params)?
+			.remove(0))
+	}
+
+	/// Blocking version of [`crate::dawn_api::DawnApi::get_pbs`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_pbs(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		course: u8,
+	) -> Result<Vec<Record>> {
+		let params = records::Params {
+			stage: Some(course),
+			player: Some(player.into()),
+			limit: Some(100000),
+			..Default::default()
+		};
+
+		self.get_list(format!("{}/records/top", self.base_url()), &params)
+	}
+}
@@ -0,0 +1,462 @@
+//! Blocking version of [`crate::global_api`].
+
+use {
+	crate::{
+		global_api::{
+			bans, bans::NewBan, filters, health, jumpstats, jumpstats::NewJumpstat, maps, players,
+			records,
+			records::{world_records, world_records::RecordHolder, NewRecord, RecordSubmission},
+			servers, Ban, Health, Jumpstat, Map, Player, Record, RecordFilter, Server,
+		},
+		http::{self, Pagination},
+		replay::Replay,
+		yeet, JumpType, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, SteamID,
+	},
+	reqwest::blocking::multipart::{Form, Part},
+	serde::Deserialize,
+	std::ops::RangeInclusive,
+};
+
+super::blocking_client! {
+	/// Blocking version of [`crate::global_api::GlobalApi`].
+	///
+	/// ```no_run
+	/// use gokz_rs::blocking::GlobalApi;
+	///
+	/// # fn run() -> gokz_rs::Result<()> {
+	/// let global_api = GlobalApi::builder()
+	/// 	.user_agent("my-kz-script")
+	/// 	.build()?;
+	///
+	/// let maps = global_api.get_maps(true)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	GlobalApi, GlobalApiBuilder, crate::global_api::API_URL, crate::global_api::health::URL
+}
+
+/// Query parameters for endpoints which don't take any.
+const NO_PARAMS: &[(&str, &str)] = &[];
+
+impl GlobalApi {
+	/// Blocking version of [`crate::global_api::GlobalApi::healthcheck`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn healthcheck(&self) -> Result<Health> {
+		let url = self
+			.health_url()
+			.expect("GlobalAPI always has a health URL.");
+
+		self.get::<health::Response>(url, &NO_PARAMS)
+			.map(Health::from)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_bans_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_bans_with(&self, params: &bans::Params) -> Result<Vec<Ban>> {
+		self.get_list(params.url(self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_bans_with`].
+	pub fn iter_bans_with<'a>(
+		&'a self,
+		params: &bans::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Ban>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_bans_with(&bans::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::create_ban`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn create_ban(&self, ban: &NewBan) -> Result<Ban> {
+		let request = self
+			.client()
+			.post(format!("{}/bans", self.base_url()))
+			.json(ban);
+
+		http::blocking::json(self.send(request)?)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_maps_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maps_with(&self, params: &maps::Params) -> Result<Vec<Map>> {
+		self.get_list(format!("{}/maps", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_maps_with`].
+	pub fn iter_maps_with<'a>(
+		&'a self,
+		params: &maps::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Map>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_maps_with(&maps::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_maps`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maps(&self, global: bool) -> Result<Vec<Map>> {
+		let params = maps::Params { is_validated: Some(global), ..Default::default() };
+		let maps = self
+			.iter_maps_with(&params, Pagination::new().page_size(1000))
+			.collect::<Result<Vec<_>>>()?;
+
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(maps)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_map`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_map(&self, name: &str) -> Result<Map> {
+		self.get(format!("{}/maps/{name}", self.base_url()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_players_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_players_with(&self, params: &players::Params) -> Result<Vec<Player>> {
+		self.get_list(format!("{}/players", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_players_with`].
+	pub fn iter_players_with<'a>(
+		&'a self,
+		params: &players::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Player>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_players_with(&players::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_player`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_player(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<Player> {
+		let (url, params) = match player.into() {
+			PlayerIdentifier::Name(name) => {
+				let params =
+					players::Params { name: Some(name), limit: Some(1), ..Default::default() };
+
+				(format!("{}/players", self.base_url()), params)
+			}
+			PlayerIdentifier::SteamID(steam_id) => (
+				format!("{}/players/steamid/{steam_id}", self.base_url()),
+				players::Params::default(),
+			),
+		};
+
+		Ok(self.get_list(url, &params)?.remove(0))
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_filters_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_filters_with(&self, params: &filters::Params) -> Result<Vec<RecordFilter>> {
+		self.get_list(params.url(self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_filters_with`].
+	pub fn iter_filters_with<'a>(
+		&'a self,
+		params: &filters::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<RecordFilter>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_filters_with(&filters::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_jumpstats_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_jumpstats_with(&self, params: &jumpstats::Params) -> Result<Vec<Jumpstat>> {
+		self.get_list(format!("{}/jumpstats", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_jumpstats_with`].
+	pub fn iter_jumpstats_with<'a>(
+		&'a self,
+		params: &jumpstats::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Jumpstat>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_jumpstats_with(&jumpstats::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_top_jumpstats_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_top_jumpstats_with(
+		&self,
+		jump_type: JumpType,
+		params: &jumpstats::TopParams,
+	) -> Result<Vec<Jumpstat>> {
+		self.get_list(format!("{}/jumpstats/{}/top", self.base_url(), jump_type.api()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_top_jumpstats`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_top_jumpstats(&self, jump_type: JumpType) -> Result<Vec<Jumpstat>> {
+		let params = jumpstats::TopParams {
+			is_crouch_bind: Some(false),
+			is_forward_bind: Some(false),
+			is_crouch_boost: Some(false),
+			limit: Some(100),
+			..Default::default()
+		};
+
+		self.get_top_jumpstats_with(jump_type, &params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::create_jumpstat`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn create_jumpstat(&self, jumpstat: &NewJumpstat) -> Result<Jumpstat> {
+		let request = self
+			.client()
+			.post(format!("{}/jumpstats", self.base_url()))
+			.json(jumpstat);
+
+		http::blocking::json(self.send(request)?)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_servers_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_servers_with(&self, params: &servers::Params) -> Result<Vec<Server>> {
+		self.get_list(params.url(self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_servers_with`].
+	pub fn iter_servers_with<'a>(
+		&'a self,
+		params: &servers::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Server>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_servers_with(&servers::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_servers_owned_by`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_servers_owned_by(
+		&self,
+		server_owner: impl Into<SteamID> + std::fmt::Debug,
+	) -> Result<Vec<Server>> {
+		let params = servers::Params { owned_by: Some(server_owner.into()), ..Default::default() };
+
+		self.get_servers_with(&params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_server`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_server(&self, name: &str) -> Result<Server> {
+		self.get(format!("{}/servers/{name}", self.base_url()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_record`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_record(&self, record_id: u32) -> Result<Record> {
+		self.get(format!("{}/records/{record_id}", self.base_url()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_place`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_place(&self, record_id: u32) -> Result<u32> {
+		#[derive(Deserialize)]
+		struct Place(u32);
+
+		let Place(place) =
+			self.get(format!("{}/records/place/{record_id}", self.base_url()), &NO_PARAMS)?;
+
+		Ok(place)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_records_with`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_records_with(&self, params: &records::Params) -> Result<Vec<Record>> {
+		self.get_list(format!("{}/records/top", self.base_url()), params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::stream_records_with`].
+	pub fn iter_records_with<'a>(
+		&'a self,
+		params: &records::Params,
+		pagination: Pagination,
+	) -> impl Iterator<Item = Result<Record>> + 'a {
+		let params = params.clone();
+
+		http::blocking::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			self.get_records_with(&records::Params {
+				offset: Some(offset),
+				limit: Some(limit),
+				..params.clone()
+			})
+		})
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_wr`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_wr(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		Ok(self.get_maptop(map, course, mode, runtype, 1)?.remove(0))
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_maptop`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maptop(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		let mut params = records::Params {
+			stage: Some(course),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(n as u32),
+			..Default::default()
+		};
+
+		match map.into() {
+			MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
+			MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
+		};
+
+		self.get_records_with(&params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_pb`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_pb(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		let mut params = records::Params {
+			stage: Some(course),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(1),
+			..Default::default()
+		};
+
+		match player.into() {
+			PlayerIdentifier::SteamID(steam_id) => params.steam_id = Some(steam_id),
+			PlayerIdentifier::Name(name) => params.player_name = Some(name),
+		};
+
+		match map.into() {
+			MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
+			MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
+		};
+
+		Ok(self.get_records_with(&params)?.remove(0))
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_wr_leaderboard`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_wr_leaderboard(
+		&self,
+		stages: impl Into<RangeInclusive<u8>> + std::fmt::Debug,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+		n: usize,
+	) -> Result<Vec<RecordHolder>> {
+		let params =
+			world_records::Params::leaderboard(stages.into(), mode.into(), runtype.into(), n);
+
+		self.get_list(params.url(self.base_url()), &params)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::submit_record`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn submit_record(&self, record: &NewRecord) -> Result<RecordSubmission> {
+		record.validate()?;
+
+		let request = self
+			.client()
+			.post(format!("{}/records", self.base_url()))
+			.json(record);
+
+		http::blocking::json(self.send(request)?)
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::upload_replay`].
+	#[tracing::instrument(level = "TRACE", skip(self, replay))]
+	pub fn upload_replay(&self, record_id: u32, replay: Vec<u8>) -> Result<()> {
+		let replay = Part::bytes(replay).file_name(format!("{record_id}.replay"));
+		let request = self
+			.client()
+			.post(format!("{}/records/{record_id}/replay", self.base_url()))
+			.multipart(Form::new().part("replay", replay));
+
+		self.send(request)?;
+
+		Ok(())
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_replay_file`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_replay_file(&self, replay_id: u32) -> Result<Vec<u8>> {
+		let url = format!("{}/records/replay/{replay_id}", self.base_url());
+
+		Ok(self.get_response(url, &NO_PARAMS)?.bytes()?.to_vec())
+	}
+
+	/// Blocking version of [`crate::global_api::GlobalApi::get_replay`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_replay(&self, replay_id: u32) -> Result<Replay> {
+		Replay::parse(&self.get_replay_file(replay_id)?)
+	}
+}
@@ -0,0 +1,59 @@
+//! Blocking version of [`crate::kzgo_api`].
+
+use crate::{
+	kzgo_api::{servers::ServerStates, CompletionStats, Map, Server, User, WorldRecord},
+	Mode, Result, SteamID,
+};
+
+super::blocking_client! {
+	/// Blocking version of [`crate::kzgo_api::KzgoApi`].
+	KzgoApi, KzgoApiBuilder, crate::kzgo_api::API_URL
+}
+
+/// Query parameters for endpoints which don't take any.
+const NO_PARAMS: &[(&str, &str)] = &[];
+
+impl KzgoApi {
+	/// Blocking version of [`crate::kzgo_api::KzgoApi::get_map`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_map(&self, map_name: &str) -> Result<Map> {
+		self.get(format!("{}/maps/{map_name}", self.base_url()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::kzgo_api::KzgoApi::get_maps`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_maps(&self) -> Result<Vec<Map>> {
+		self.get_list(format!("{}/maps", self.base_url()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::kzgo_api::KzgoApi::get_servers`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_servers(&self) -> Result<Vec<Server>> {
+		self.get::<ServerStates>(format!("{}/servers", self.base_url()), &NO_PARAMS)
+			.map(|result| result.server_states)
+	}
+
+	/// Blocking version of [`crate::kzgo_api::KzgoApi::get_completions`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_completions(
+		&self,
+		mode: impl Into<Mode> + std::fmt::Debug,
+	) -> Result<CompletionStats> {
+		self.get(format!("{}/completions/{}", self.base_url(), mode.into().api()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::kzgo_api::KzgoApi::get_world_records`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_world_records(
+		&self,
+		mode: impl Into<Mode> + std::fmt::Debug,
+	) -> Result<Vec<WorldRecord>> {
+		self.get(format!("{}/wrs/{}", self.base_url(), mode.into().api()), &NO_PARAMS)
+	}
+
+	/// Blocking version of [`crate::kzgo_api::KzgoApi::get_user`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub fn get_user(&self, steam_id: impl Into<SteamID> + std::fmt::Debug) -> Result<User> {
+		self.get(format!("{}/steam/{}", self.base_url(), steam_id.into().as_id64()), &NO_PARAMS)
+	}
+}
@@ -0,0 +1,231 @@
+//! Synchronous versions of the API clients.
+//!
+//! Every client in this module sends its requests with [`reqwest::blocking`] and exposes the same
+//! endpoints as its async counterpart. All parameter and response types are shared with the async
+//! modules, and requests are retried and rate limited the same way. Response caching and request
+//! coalescing are not available, since a blocking client only ever waits on one request at a
+//! time.
+//!
+//! Paginated endpoints are exposed as `iter_*_with` methods returning an [`Iterator`] instead of
+//! a [`Stream`](futures::Stream).
+//!
+//! [`reqwest::blocking`] cannot be used while a Tokio runtime is entered, including from
+//! `tokio::task::spawn_blocking`. Instead of panicking, every request then fails with
+//! [`Error::BlockingInAsyncContext`](crate::Error::BlockingInAsyncContext).
+
+#[cfg(feature = "global-api")]
+mod global_api;
+#[cfg(feature = "global-api")]
+pub use global_api::{GlobalApi, GlobalApiBuilder};
+
+#[cfg(feature = "kzgo-api")]
+mod kzgo_api;
+#[cfg(feature = "kzgo-api")]
+pub use kzgo_api::{KzgoApi, KzgoApiBuilder};
+
+#[cfg(feature = "dawn-api")]
+mod dawn_api;
+#[cfg(feature = "dawn-api")]
+pub use dawn_api::{DawnApi, DawnApiBuilder};
+
+/// Generates a blocking API client type and its builder.
+///
+/// This mirrors [`api_client!`](crate::http::api_client), except that the generated client holds
+/// a [`reqwest::blocking::Client`] and has no cache.
+macro_rules! blocking_client {
+	(@health_url) => {
+		None
+	};
+
+	(@health_url $health_url:expr) => {
+		Some(String::from($health_url))
+	};
+
+	(
+		$(#[$meta:meta])*
+		$name:ident, $builder:ident, $default_url:expr $(, $default_health_url:expr)?
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone)]
+		pub struct $name {
+			client: ::reqwest::blocking::Client,
+			base_url: String,
+			health_url: Option<String>,
+			retry: $crate::http::RetryPolicy,
+			rate_limiter: Option<$crate::http::RateLimiter>,
+		}
+
+		impl $name {
+			/// Constructs a new client with the default configuration.
+			pub fn new() -> $crate::Result<Self> {
+				Self::builder().build()
+			}
+
+			/// Returns a builder to configure a new client.
+			pub fn builder() -> $builder {
+				$builder::default()
+			}
+
+			/// The base URL every request path is appended to.
+			pub fn base_url(&self) -> &str {
+				&self.base_url
+			}
+
+			/// The URL used for health checks, if the API has one.
+			pub fn health_url(&self) -> Option<&str> {
+				self.health_url.as_deref()
+			}
+
+			/// The underlying [`Client`](reqwest::blocking::Client) used to make requests.
+			pub fn client(&self) -> &::reqwest::blocking::Client {
+				&self.client
+			}
+
+			/// The [`RetryPolicy`](crate::http::RetryPolicy) applied to every request.
+			pub fn retry_policy(&self) -> &$crate::http::RetryPolicy {
+				&self.retry
+			}
+
+			/// The [`RateLimiter`](crate::http::RateLimiter) every request waits on, if any.
+			///
+			/// All clones of this client share the same rate limiter.
+			pub fn rate_limiter(&self) -> Option<&$crate::http::RateLimiter> {
+				self.rate_limiter.as_ref()
+			}
+
+			/// Sends a `GET` request to `url` with the given query `params`.
+			#[allow(dead_code)]
+			fn get_response(
+				&self,
+				url: impl ::reqwest::IntoUrl,
+				params: &impl ::serde::Serialize,
+			) -> $crate::Result<::reqwest::blocking::Response> {
+				let request = self.client.get(url).query(params).build()?;
+
+				$crate::http::blocking::send(
+					&self.client,
+					request,
+					&self.retry,
+					self.rate_limiter.as_ref(),
+				)
+			}
+
+			/// Sends a `GET` request to `url` and deserializes the response.
+			fn get<T: ::serde::de::DeserializeOwned>(
+				&self,
+				url: impl ::reqwest::IntoUrl,
+				params: &impl ::serde::Serialize,
+			) -> $crate::Result<T> {
+				$crate::http::blocking::json(self.get_response(url, params)?)
+			}
+
+			/// Same as `get`, but fails with [`Error::EmptyResponse`](crate::Error::EmptyResponse)
+			/// if the API returns an empty list.
+			#[allow(dead_code)]
+			fn get_list<T: ::serde::de::DeserializeOwned>(
+				&self,
+				url: impl ::reqwest::IntoUrl,
+				params: &impl ::serde::Serialize,
+			) -> $crate::Result<Vec<T>> {
+				let items = self.get::<Vec<T>>(url, params)?;
+
+				if items.is_empty() {
+					$crate::yeet!(EmptyResponse);
+				}
+
+				Ok(items)
+			}
+
+			/// Sends an arbitrary request. Like with [`request!`](crate::http::request), it is
+			/// never retried.
+			#[allow(dead_code)]
+			fn send(
+				&self,
+				request: ::reqwest::blocking::RequestBuilder,
+			) -> $crate::Result<::reqwest::blocking::Response> {
+				$crate::http::blocking::send(
+					&self.client,
+					request.build()?,
+					&$crate::http::RetryPolicy::none(),
+					self.rate_limiter.as_ref(),
+				)
+			}
+		}
+
+		#[doc = concat!("Builder for [`", stringify!($name), "`].")]
+		#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+		pub struct $builder {
+			config: $crate::http::Config,
+		}
+
+		impl Default for $builder {
+			fn default() -> Self {
+				let mut config = $crate::http::Config::new($default_url);
+				config.health_url =
+					$crate::blocking::blocking_client!(@health_url $($default_health_url)?);
+
+				Self { config }
+			}
+		}
+
+		impl $builder {
+			/// Sets the base URL every request path is appended to.
+			pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+				self.config.base_url = base_url.into();
+				self
+			}
+
+			/// Sets the URL used for health checks.
+			pub fn health_url(mut self, health_url: impl Into<String>) -> Self {
+				self.config.health_url = Some(health_url.into());
+				self
+			}
+
+			/// Sets an API key which will be sent with every request.
+			pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+				self.config.api_key = Some(api_key.into());
+				self
+			}
+
+			/// Sets the timeout for each request.
+			pub fn timeout(mut self, timeout: ::std::time::Duration) -> Self {
+				self.config.timeout = Some(timeout);
+				self
+			}
+
+			/// Sets the `User-Agent` header sent with every request.
+			pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+				self.config.user_agent = Some(user_agent.into());
+				self
+			}
+
+			/// Sets how failed requests are retried.
+			pub fn retry(mut self, retry: $crate::http::RetryPolicy) -> Self {
+				self.config.retry = retry;
+				self
+			}
+
+			/// Limits how many requests may be sent to a single host.
+			pub fn rate_limit(mut self, rate_limit: $crate::http::RateLimit) -> Self {
+				self.config.rate_limit = Some(rate_limit);
+				self
+			}
+
+			/// Builds the client.
+			///
+			/// This will fail if the base URL is invalid or the underlying
+			/// [`Client`](reqwest::blocking::Client) cannot be constructed.
+			pub fn build(self) -> $crate::Result<$name> {
+				Ok($name {
+					client: self.config.blocking_client()?,
+					base_url: self.config.base_url()?,
+					health_url: self.config.health_url()?,
+					retry: self.config.retry,
+					rate_limiter: self.config.rate_limit.map($crate::http::RateLimiter::new),
+				})
+			}
+		}
+	};
+}
+
+pub(crate) use blocking_client;
@@ -0,0 +1,94 @@
+//! This module contains types for counting completed courses per [`Tier`].
+//!
+//! These are returned by [KZ:GO](crate::kzgo_api)'s `/completions/:mode` endpoint and can also
+//! be computed from GlobalAPI data.
+
+use crate::{Mode, Runtype, Tier};
+
+/// The amount of completed courses for a single mode.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CompletionStats {
+	pub mode: Mode,
+	pub tp: CompletionCount,
+	pub pro: CompletionCount,
+}
+
+impl CompletionStats {
+	/// Creates empty stats for the given `mode`.
+	pub fn new(mode: Mode) -> Self {
+		Self { mode, tp: CompletionCount::default(), pro: CompletionCount::default() }
+	}
+
+	/// The counts for the given `runtype`.
+	pub const fn runtype(&self, runtype: Runtype) -> &CompletionCount {
+		match runtype {
+			Runtype::TP => &self.tp,
+			Runtype::Pro => &self.pro,
+		}
+	}
+
+	/// The counts for the given `runtype`.
+	pub fn runtype_mut(&mut self, runtype: Runtype) -> &mut CompletionCount {
+		match runtype {
+			Runtype::TP => &mut self.tp,
+			Runtype::Pro => &mut self.pro,
+		}
+	}
+}
+
+/// The amount of completed courses per [`Tier`].
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CompletionCount {
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "1")))]
+	pub one: u16,
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "2")))]
+	pub two: u16,
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "3")))]
+	pub three: u16,
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "4")))]
+	pub four: u16,
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "5")))]
+	pub five: u16,
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "6")))]
+	pub six: u16,
+	#[cfg_attr(feature = "serde", serde(rename(deserialize = "7")))]
+	pub seven: u16,
+	pub total: u16,
+}
+
+impl CompletionCount {
+	/// The count for the given `tier`.
+	pub const fn tier(&self, tier: Tier) -> u16 {
+		match tier {
+			Tier::VeryEasy => self.one,
+			Tier::Easy => self.two,
+			Tier::Medium => self.three,
+			Tier::Hard => self.four,
+			Tier::VeryHard => self.five,
+			Tier::Extreme => self.six,
+			Tier::Death => self.seven,
+		}
+	}
+
+	/// Counts one more course of the given `tier`.
+	pub fn add(&mut self, tier: Tier) {
+		let count = match tier {
+			Tier::VeryEasy => &mut self.one,
+			Tier::Easy => &mut self.two,
+			Tier::Medium => &mut self.three,
+			Tier::Hard => &mut self.four,
+			Tier::VeryHard => &mut self.five,
+			Tier::Extreme => &mut self.six,
+			Tier::Death => &mut self.seven,
+		};
+
+		*count += 1;
+		self.total += 1;
+	}
+}
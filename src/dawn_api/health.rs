@@ -2,14 +2,26 @@
 //!
 //! This module gives programmatic access to this endpoint.
 
-use crate::{http, Result};
+use {
+	super::DawnApi,
+	crate::{http, Result},
+};
 
-/// Returns true if DawnAPI is online.
-pub async fn healthcheck(client: &http::Client) -> Result<bool> {
-	let response = http::get! {
-		url = "https://dawn.sh/";
-		client = client;
-	}?;
+/// The URL for the API's health endpoint.
+pub const URL: &str = "https://dawn.sh/";
 
-	Ok(response == "balls")
+impl DawnApi {
+	/// Returns true if DawnAPI is online.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn healthcheck(&self) -> Result<bool> {
+		let response = http::get! {
+			url = self.health_url().expect("DawnAPI always has a health URL.");
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		Ok(response == "balls")
+	}
 }
@@ -5,8 +5,12 @@
 //! - `/maps/:map_identifier`
 
 use {
-	super::API_URL,
-	crate::{http, yeet, MapIdentifier, PlayerIdentifier, Result, SteamID, Tier},
+	super::{DawnApi, API_URL},
+	crate::{
+		http::{self, Pagination},
+		yeet, MapIdentifier, PlayerIdentifier, Result, SteamID, Tier,
+	},
+	futures::{Stream, TryStreamExt},
 	serde::{Deserialize, Serialize},
 	std::ops::Deref,
 };
@@ -91,12 +95,22 @@ impl Map {
 
 	/// Returns a link to fetch this map from DawnAPI.
 	pub fn api(&self) -> String {
-		format!("{API_URL}/maps/{}", self.id)
+		self.api_with_base_url(API_URL)
+	}
+
+	/// Same as [`Self::api`] but with a custom base URL instead of [`API_URL`].
+	pub fn api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/{}", self.id)
 	}
 
 	/// Returns a link to fetch this map from the GlobalAPI.
 	pub fn global_api(&self) -> String {
-		format!("{API_URL}/maps/name/{}", self.name)
+		self.global_api_with_base_url("https://kztimerglobal.com/api/v2")
+	}
+
+	/// Same as [`Self::global_api`] but with a custom base URL.
+	pub fn global_api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/name/{}", self.name)
 	}
 
 	/// Returns a link to the map's KZ:GO page.
@@ -169,69 +183,101 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/maps` route
-///
-/// Fetches maps with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maps_with(params: &Params, client: &http::Client) -> Result<Vec<Map>> {
-	let maps = http::get! {
-		url = format!("{API_URL}/maps");
-		params = params;
-		deserialize = Vec<Map>;
-		client = client;
-	}?;
-
-	if maps.is_empty() {
-		yeet!(EmptyResponse);
-	}
-
-	Ok(maps)
-}
+impl DawnApi {
+	/// `/maps` route
+	///
+	/// Fetches maps with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maps_with(&self, params: &Params) -> Result<Vec<Map>> {
+		let maps = http::get! {
+			url = format!("{}/maps", self.base_url());
+			params = params;
+			deserialize = Vec<Map>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			cache = self.cache(), "/maps", std::time::Duration::from_secs(60 * 60);
+			in_flight = self.in_flight();
+		}?;
+
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(maps)
+	}
 
-/// `/maps` route
-///
-/// Fetches maps with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maps(global: bool, client: &http::Client) -> Result<Vec<Map>> {
-	let params = Params { global: Some(global), limit: Some(9999), ..Default::default() };
+	/// `/maps` route
+	///
+	/// Same as [`Self::get_maps_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] maps, starting at `params.offset`, until there are no more
+	/// maps left. `params.limit` is ignored.
+	pub fn stream_maps_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Map>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_maps_with(&params).await }
+		})
+	}
 
-	get_maps_with(&params, client).await
-}
+	/// `/maps` route
+	///
+	/// Fetches maps with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maps(&self, global: bool) -> Result<Vec<Map>> {
+		let params = Params { global: Some(global), ..Default::default() };
+		let maps = self
+			.stream_maps_with(&params, Pagination::new().page_size(1000))
+			.try_collect::<Vec<_>>()
+			.await?;
+
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(maps)
+	}
 
-/// `/maps/:map_identifier` route
-///
-/// Fetches a single map either by its name or ID.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_map(
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<Map> {
-	let map = http::get! {
-		url = format!("{API_URL}/maps/{}", map.into());
-		deserialize = Map;
-		client = client;
-	}?;
-
-	Ok(map)
-}
+	/// `/maps/:map_identifier` route
+	///
+	/// Fetches a single map either by its name or ID.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_map(&self, map: impl Into<MapIdentifier> + std::fmt::Debug) -> Result<Map> {
+		let map = http::get! {
+			url = format!("{}/maps/{}", self.base_url(), map.into());
+			deserialize = Map;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		Ok(map)
+	}
 
-/// `/maps` route
-///
-/// Fetches all maps made by a specific player.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maps_by(
-	mapper: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<Vec<Map>> {
-	let params = Params { mapper: Some(mapper.into()), ..Default::default() };
-
-	get_maps_with(&params, client).await
+	/// `/maps` route
+	///
+	/// Fetches all maps made by a specific player.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maps_by(
+		&self,
+		mapper: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<Vec<Map>> {
+		let params = Params { mapper: Some(mapper.into()), ..Default::default() };
+
+		self.get_maps_with(&params).await
+	}
 }
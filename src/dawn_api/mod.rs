@@ -1,5 +1,7 @@
 //! This module contains various functions and submodules covering
 //! [DawnAPI](https://dawn.sh/api/docs/swagger-ui).
+//!
+//! All endpoints are exposed as methods on [`DawnApi`].
 
 /// The base URL for all API requests.
 pub const API_URL: &str = "https://dawn.sh/api/kz";
@@ -7,23 +9,24 @@ pub const API_URL: &str = "https://dawn.sh/api/kz";
 /// The URL for the API's SwaggerUI website.
 pub const SWAGGER_URL: &str = "https://dawn.sh/api/docs/swagger-ui";
 
+crate::http::api_client! {
+	/// A client for [DawnAPI](https://dawn.sh/api/docs/swagger-ui).
+	DawnApi, DawnApiBuilder, API_URL, health::URL
+}
+
 pub mod health;
-pub use health::healthcheck;
 
 pub mod maps;
-pub use maps::{get_map, get_maps, get_maps_by, get_maps_with, Map};
+pub use maps::Map;
 
 pub mod servers;
-pub use servers::{get_server, get_servers_owned_by, get_servers_with, Server};
+pub use servers::Server;
 
 pub mod players;
-pub use players::{
-	get_completed, get_pb_progresion, get_player, get_players_with, Completion, CompletionCount,
-	Player, PlayerWithCompletion,
-};
+pub use players::{Completion, CompletionCount, Player, PlayerWithCompletion};
 
 pub mod records;
-pub use records::{get_maptop, get_pb, get_pbs, get_record, get_records_with, get_wr, Record};
+pub use records::Record;
 
 mod serde {
 	pub mod chrono {
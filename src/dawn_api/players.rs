@@ -6,11 +6,12 @@
 //! - `/players/:identifier/completion`
 
 use {
-	super::{Record, API_URL},
+	super::{DawnApi, Record, API_URL},
 	crate::{
-		http::{self, append_pairs},
+		http::{self, append_pairs, Pagination},
 		yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, SteamID, Tier,
 	},
+	futures::Stream,
 	reqwest::Url,
 	serde::{Deserialize, Serialize},
 	std::ops::{Deref, DerefMut},
@@ -60,7 +61,13 @@ macro_rules! impl_player {
 			/// Returns a link to fetch this player from the GlobalAPI.
 			#[inline]
 			pub fn api(&self) -> String {
-				format!("{API_URL}/players/steam_id/{}", self.steam_id)
+				self.api_with_base_url(API_URL)
+			}
+
+			/// Same as [`Self::api`] but with a custom base URL instead of [`API_URL`].
+			#[inline]
+			pub fn api_with_base_url(&self, base_url: &str) -> String {
+				format!("{base_url}/players/steam_id/{}", self.steam_id)
 			}
 
 			/// Returns a link to the player's KZ:GO profile.
@@ -83,46 +90,6 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/players` route
-///
-/// Fetches players with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_players_with(params: &Params, client: &http::Client) -> Result<Vec<Player>> {
-	let players = http::get! {
-		url = format!("{API_URL}/players");
-		params = params;
-		deserialize = Vec<Player>;
-		client = client;
-	}?;
-
-	if players.is_empty() {
-		yeet!(EmptyResponse);
-	}
-
-	Ok(players)
-}
-
-/// `/players/:player_identifier` route.
-///
-/// Fetches a single player by their name or [`SteamID`].
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_player(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<PlayerWithCompletion> {
-	let player = http::get! {
-		url = format!("{API_URL}/players/{}", player.into());
-		deserialize = PlayerWithCompletion;
-		client = client;
-	}?;
-
-	Ok(player)
-}
-
 #[allow(missing_docs)]
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CompletionParams {
@@ -136,6 +103,19 @@ pub struct CompletionParams {
 	pub offset: Option<i64>,
 }
 
+impl CompletionParams {
+	/// The URL for `/players/:player/completion`, including the list parameters which are
+	/// skipped when serializing the rest of the query string.
+	pub(crate) fn url(&self, base_url: &str, player: PlayerIdentifier) -> Url {
+		let mut url = Url::parse(&format!("{base_url}/players/{player}/completion"))
+			.expect("This is a valid URL.");
+
+		append_pairs!(&mut url, &self.stages, "stages");
+
+		url
+	}
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CompletedCourse {
@@ -150,36 +130,6 @@ pub struct CompletedCourse {
 	pub player_name: String,
 }
 
-/// `/players/:player_identifier/completion` route.
-///
-/// Fetches all courses (not) completed by a given `player`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_completed(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	params: &CompletionParams,
-	client: &http::Client,
-) -> Result<Vec<CompletedCourse>> {
-	let mut url = Url::parse(&format!("{API_URL}/players/{}/completion", player.into()))
-		.expect("This is a valid URL.");
-
-	append_pairs!(&mut url, &params.stages, "stages");
-
-	let completed = http::get! {
-		url = url;
-		params = params;
-		deserialize = Vec<CompletedCourse>;
-		client = client;
-	}?;
-
-	if completed.is_empty() {
-		yeet!(EmptyResponse);
-	}
-
-	Ok(completed)
-}
-
 #[allow(missing_docs)]
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ProgressionParams {
@@ -212,27 +162,130 @@ impl DerefMut for ProgressionRecord {
 	}
 }
 
-/// `/records/progression/:player/:mode` route
-///
-/// Fetches all of a player's personal bests in chronological order for the given mode.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_pb_progresion(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	params: &ProgressionParams,
-	client: &crate::http::Client,
-) -> Result<Vec<ProgressionRecord>> {
-	let records = http::get! {
-		url = format!("{API_URL}/players/{}/progression/{}/{}", player.into(), map.into(), mode.into().api());
-		params = &params;
-		deserialize = Vec<ProgressionRecord>;
-		client = client;
-	}?;
-
-	if records.is_empty() {
-		yeet!(EmptyResponse);
+impl DawnApi {
+	/// `/players` route
+	///
+	/// Fetches players with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_players_with(&self, params: &Params) -> Result<Vec<Player>> {
+		let players = http::get! {
+			url = format!("{}/players", self.base_url());
+			params = params;
+			deserialize = Vec<Player>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if players.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(players)
+	}
+
+	/// `/players` route
+	///
+	/// Same as [`Self::get_players_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] players, starting at `params.offset`, until there are no more
+	/// players left. `params.limit` is ignored.
+	pub fn stream_players_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Player>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_players_with(&params).await }
+		})
+	}
+
+	/// `/players/:player_identifier` route.
+	///
+	/// Fetches a single player by their name or [`SteamID`].
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_player(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<PlayerWithCompletion> {
+		let player = http::get! {
+			url = format!("{}/players/{}", self.base_url(), player.into());
+			deserialize = PlayerWithCompletion;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		Ok(player)
 	}
 
-	Ok(records)
+	/// `/players/:player_identifier/completion` route.
+	///
+	/// Fetches all courses (not) completed by a given `player`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_completed(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		params: &CompletionParams,
+	) -> Result<Vec<CompletedCourse>> {
+		let completed = http::get! {
+			url = params.url(self.base_url(), player.into());
+			params = params;
+			deserialize = Vec<CompletedCourse>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if completed.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(completed)
+	}
+
+	/// `/records/progression/:player/:mode` route
+	///
+	/// Fetches all of a player's personal bests in chronological order for the given mode.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_pb_progresion(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		params: &ProgressionParams,
+	) -> Result<Vec<ProgressionRecord>> {
+		let records = http::get! {
+			url = format!(
+				"{}/players/{}/progression/{}/{}",
+				self.base_url(),
+				player.into(),
+				map.into(),
+				mode.into().api()
+			);
+			params = &params;
+			deserialize = Vec<ProgressionRecord>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if records.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(records)
+	}
 }
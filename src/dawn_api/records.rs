@@ -7,11 +7,13 @@
 //! - `/records/progression/:player/:mode`
 
 use {
-	super::API_URL,
+	super::{DawnApi, API_URL},
 	crate::{
-		http, yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, ServerIdentifier,
-		SteamID, Tier,
+		http::{self, Pagination},
+		yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, ServerIdentifier, SteamID,
+		Tier,
 	},
+	futures::Stream,
 	serde::{Deserialize, Serialize},
 };
 
@@ -67,12 +69,22 @@ impl Record {
 
 	/// Returns a link to fetch the map the record was done on from DawnAPI.
 	pub fn api(&self) -> String {
-		format!("{API_URL}/maps/name/{}", self.map_name)
+		self.api_with_base_url(API_URL)
+	}
+
+	/// Same as [`Self::api`] but with a custom base URL instead of [`API_URL`].
+	pub fn api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/name/{}", self.map_name)
 	}
 
 	/// Returns a link to fetch the map the record was done on from the GlobalAPI.
 	pub fn global_api(&self) -> String {
-		format!("https://kztimerglobal.com/api/v2/maps/name/{}", self.map_name)
+		self.global_api_with_base_url("https://kztimerglobal.com/api/v2")
+	}
+
+	/// Same as [`Self::global_api`] but with a custom base URL.
+	pub fn global_api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/name/{}", self.map_name)
 	}
 
 	/// Returns a link to the KZ:GO page of the map this record was done on.
@@ -81,18 +93,6 @@ impl Record {
 	}
 }
 
-/// `/records/:record_id` route
-///
-/// Fetches a specific record by id.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_record(record_id: u32, client: &crate::http::Client) -> Result<Record> {
-	http::get! {
-		url = format!("{API_URL}/records/{record_id}");
-		deserialize = Record;
-		client = client;
-	}
-}
-
 #[allow(missing_docs)]
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Params {
@@ -118,143 +118,198 @@ pub enum SortRecordsBy {
 	Slowest,
 }
 
-/// `/records` route
-///
-/// Fetches records with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_records_with(
-	params: &Params,
-	client: &crate::http::Client,
-) -> Result<Vec<Record>> {
-	let records = http::get! {
-		url = format!("{API_URL}/records");
-		params = params;
-		deserialize = Vec<Record>;
-		client = client;
-	}?;
-
-	if records.is_empty() {
-		yeet!(EmptyResponse);
+impl DawnApi {
+	/// `/records/:record_id` route
+	///
+	/// Fetches a specific record by id.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_record(&self, record_id: u32) -> Result<Record> {
+		http::get! {
+			url = format!("{}/records/{record_id}", self.base_url());
+			deserialize = Record;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}
 	}
 
-	Ok(records)
-}
+	/// `/records` route
+	///
+	/// Fetches records with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_records_with(&self, params: &Params) -> Result<Vec<Record>> {
+		let records = http::get! {
+			url = format!("{}/records", self.base_url());
+			params = params;
+			deserialize = Vec<Record>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
 
-/// `/records/top` route
-///
-/// Fetches the world record for the given parameters.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_wr(
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	stage: u8,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	runtype: impl Into<Runtype> + std::fmt::Debug,
-	client: &crate::http::Client,
-) -> Result<Record> {
-	get_maptop(map, stage, mode, runtype, 1, client)
-		.await
-		.map(|mut records| records.remove(0))
-}
+		if records.is_empty() {
+			yeet!(EmptyResponse);
+		}
 
-/// `/records/top` route
-///
-/// Fetches the top `n` records for the given parameters.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maptop(
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	stage: u8,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	runtype: impl Into<Runtype> + std::fmt::Debug,
-	n: usize,
-	client: &crate::http::Client,
-) -> Result<Vec<Record>> {
-	let params = Params {
-		map: Some(map.into()),
-		stage: Some(stage),
-		mode: Some(mode.into()),
-		runtype: Some(runtype.into()),
-		limit: Some(n as u64),
-		..Default::default()
-	};
-
-	let records = http::get! {
-		url = format!("{API_URL}/records/top");
-		params = &params;
-		deserialize = Vec<Record>;
-		client = client;
-	}?;
-
-	if records.is_empty() {
-		yeet!(EmptyResponse);
+		Ok(records)
 	}
 
-	Ok(records)
-}
+	/// `/records` route
+	///
+	/// Same as [`Self::get_records_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] records, starting at `params.offset`, until there are no more
+	/// records left. `params.limit` is ignored.
+	pub fn stream_records_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Record>> + 'a {
+		let params = params.clone();
 
-/// `/records/top` route
-///
-/// Fetches a player's personal best on the given map.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_pb(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	course: u8,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	runtype: impl Into<Runtype> + std::fmt::Debug,
-	client: &crate::http::Client,
-) -> Result<Record> {
-	let params = Params {
-		map: Some(map.into()),
-		stage: Some(course),
-		player: Some(player.into()),
-		mode: Some(mode.into()),
-		runtype: Some(runtype.into()),
-		limit: Some(1),
-		..Default::default()
-	};
-
-	let mut records = http::get! {
-		url = format!("{API_URL}/records/top");
-		params = &params;
-		deserialize = Vec<Record>;
-		client = client;
-	}?;
-
-	if records.is_empty() {
-		yeet!(EmptyResponse);
+		http::pagination::paginate(
+			params
+				.offset
+				.and_then(|offset| u32::try_from(offset).ok())
+				.unwrap_or(0),
+			pagination,
+			move |offset, limit| {
+				let params = Params {
+					offset: Some(offset as i64),
+					limit: Some(limit as u64),
+					..params.clone()
+				};
+				async move { self.get_records_with(&params).await }
+			},
+		)
 	}
 
-	Ok(records.remove(0))
-}
+	/// `/records/top` route
+	///
+	/// Fetches the world record for the given parameters.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_wr(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		stage: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		self.get_maptop(map, stage, mode, runtype, 1)
+			.await
+			.map(|mut records| records.remove(0))
+	}
 
-/// `/records/top` route
-///
-/// Fetches all of a player's personal bests.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_pbs(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	course: u8,
-	client: &crate::http::Client,
-) -> Result<Vec<Record>> {
-	let params = Params {
-		stage: Some(course),
-		player: Some(player.into()),
-		limit: Some(100000),
-		..Default::default()
-	};
-
-	let records = http::get! {
-		url = format!("{API_URL}/records/top");
-		params = &params;
-		deserialize = Vec<Record>;
-		client = client;
-	}?;
-
-	if records.is_empty() {
-		yeet!(EmptyResponse);
+	/// `/records/top` route
+	///
+	/// Fetches the top `n` records for the given parameters.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maptop(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		stage: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		let params = Params {
+			map: Some(map.into()),
+			stage: Some(stage),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(n as u64),
+			..Default::default()
+		};
+
+		let records = http::get! {
+			url = format!("{}/records/top", self.base_url());
+			params = &params;
+			deserialize = Vec<Record>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if records.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(records)
 	}
 
-	Ok(records)
+	/// `/records/top` route
+	///
+	/// Fetches a player's personal best on the given map.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_pb(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		let params = Params {
+			map: Some(map.into()),
+			stage: Some(course),
+			player: Some(player.into()),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(1),
+			..Default::default()
+		};
+
+		let mut records = http::get! {
+			url = format!("{}/records/top", self.base_url());
+			params = &params;
+			deserialize = Vec<Record>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if records.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(records.remove(0))
+	}
+
+	/// `/records/top` route
+	///
+	/// Fetches all of a player's personal bests.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_pbs(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		course: u8,
+	) -> Result<Vec<Record>> {
+		let params = Params {
+			stage: Some(course),
+			player: Some(player.into()),
+			limit: Some(100000),
+			..Default::default()
+		};
+
+		let records = http::get! {
+			url = format!("{}/records/top", self.base_url());
+			params = &params;
+			deserialize = Vec<Record>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if records.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(records)
+	}
 }
@@ -5,8 +5,12 @@
 //! - `/servers/:server_identifier`
 
 use {
-	super::API_URL,
-	crate::{http, yeet, PlayerIdentifier, Result, ServerIdentifier, SteamID},
+	super::DawnApi,
+	crate::{
+		http::{self, Pagination},
+		yeet, PlayerIdentifier, Result, ServerIdentifier, SteamID,
+	},
+	futures::Stream,
 	serde::{Deserialize, Serialize},
 };
 
@@ -27,57 +31,83 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/servers` route
-///
-/// Fetches servers with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_servers_with(params: &Params, client: &http::Client) -> Result<Vec<Server>> {
-	let servers = http::get! {
-		url = format!("{API_URL}/servers");
-		params = params;
-		deserialize = Vec<Server>;
-		client = client;
-	}?;
+impl DawnApi {
+	/// `/servers` route
+	///
+	/// Fetches servers with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_servers_with(&self, params: &Params) -> Result<Vec<Server>> {
+		let servers = http::get! {
+			url = format!("{}/servers", self.base_url());
+			params = params;
+			deserialize = Vec<Server>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
 
-	if servers.is_empty() {
-		yeet!(EmptyResponse);
+		if servers.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(servers)
 	}
 
-	Ok(servers)
-}
+	/// `/servers` route
+	///
+	/// Same as [`Self::get_servers_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] servers, starting at `params.offset`, until there are no more
+	/// servers left. `params.limit` is ignored.
+	pub fn stream_servers_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Server>> + 'a {
+		let params = params.clone();
 
-/// `/servers` route
-///
-/// Fetches servers owned by the given `server_owner`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_servers_owned_by(
-	server_owner: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<Vec<Server>> {
-	let params = Params { owner: Some(server_owner.into()), ..Default::default() };
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_servers_with(&params).await }
+		})
+	}
 
-	get_servers_with(&params, client).await
-}
+	/// `/servers` route
+	///
+	/// Fetches servers owned by the given `server_owner`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_servers_owned_by(
+		&self,
+		server_owner: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<Vec<Server>> {
+		let params = Params { owner: Some(server_owner.into()), ..Default::default() };
 
-/// `/servers/:server_identifier` route
-///
-/// Fetches a single server by its name or ID.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_server(
-	server: impl Into<ServerIdentifier> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<Server> {
-	let server = http::get! {
-		url = format!("{API_URL}/servers/{}", server.into());
-		deserialize = Server;
-		client = client;
-	}?;
+		self.get_servers_with(&params).await
+	}
+
+	/// `/servers/:server_identifier` route
+	///
+	/// Fetches a single server by its name or ID.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_server(
+		&self,
+		server: impl Into<ServerIdentifier> + std::fmt::Debug,
+	) -> Result<Server> {
+		let server = http::get! {
+			url = format!("{}/servers/{}", self.base_url(), server.into());
+			deserialize = Server;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
 
-	Ok(server)
+		Ok(server)
+	}
 }
@@ -36,6 +36,14 @@ pub enum Error {
 	#[error("`{0}` is not a valid Tier.")]
 	InvalidTier(String),
 
+	/// Some input failed to parse into a [`Rank`](crate::Rank).
+	#[error("`{0}` is not a valid Rank.")]
+	InvalidRank(String),
+
+	/// Some input failed to parse into a [`JumpType`](crate::JumpType).
+	#[error("`{0}` is not a valid JumpType.")]
+	InvalidJumpType(String),
+
 	/// Some input failed to parse into a [`MapIdentifier`](crate::MapIdentifier).
 	#[error("`{0}` is out of range for a valid MapID.")]
 	InvalidMapID(String),
@@ -44,6 +52,41 @@ pub enum Error {
 	#[error("`{0}` is out of range for a valid ServerID.")]
 	InvalidServerID(String),
 
+	/// A tickrate the API does not accept records for.
+	#[error("`{0}` is not a valid tickrate.")]
+	InvalidTickrate(String),
+
+	/// A run time that is not a positive number of seconds.
+	#[error("`{0}` is not a valid time.")]
+	InvalidTime(String),
+
+	/// A stage that does not exist on the given map.
+	#[error("`{0}` is not a valid stage.")]
+	InvalidStage(String),
+
+	/// A record for a map, stage, mode, tickrate and runtype the API does not rank.
+	#[error("{0} is not ranked.")]
+	NotRanked(String),
+
+	/// Some input failed to parse into a [`Replay`](crate::replay::Replay).
+	#[error("Invalid replay: {0}.")]
+	InvalidReplay(String),
+
+	/// Some input failed to parse into a URL.
+	#[cfg(feature = "reqwest")]
+	#[error("`{0}` is not a valid URL.")]
+	InvalidUrl(String),
+
+	/// An API does not support the requested operation, e.g. fetching records from KZ:GO.
+	#[cfg(feature = "reqwest")]
+	#[error("{0} is not supported by this API.")]
+	Unsupported(String),
+
+	/// A [blocking](crate::blocking) client was used while a Tokio runtime was entered.
+	#[cfg(feature = "blocking")]
+	#[error("Blocking clients cannot be used inside an async runtime; use the async clients.")]
+	BlockingInAsyncContext,
+
 	/// An HTTP Request failed.
 	#[cfg(feature = "reqwest")]
 	#[error("HTTP Request failed{}: {message}", code.map(|code| format!(" with code {}", code.as_u16())).unwrap_or_default())]
@@ -59,10 +102,71 @@ pub enum Error {
 		message: String,
 	},
 
+	/// The requested resource does not exist.
+	#[cfg(feature = "reqwest")]
+	#[error("`{url}` was not found.")]
+	NotFound {
+		/// The URL of the failed request.
+		url: String,
+	},
+
+	/// The API rejected a request because it was missing a valid API key.
+	#[cfg(feature = "reqwest")]
+	#[error("Not authorized to access `{url}`.")]
+	Unauthorized {
+		/// The URL of the failed request.
+		url: String,
+	},
+
+	/// The API rejected a request because too many requests have been sent.
+	#[cfg(feature = "reqwest")]
+	#[error("Rate limited by `{url}`{}.", retry_after.map(|delay| format!(" (retry after {}s)", delay.as_secs())).unwrap_or_default())]
+	RateLimited {
+		/// The URL of the failed request.
+		url: String,
+
+		/// How long the API asked us to wait before sending another request.
+		retry_after: Option<std::time::Duration>,
+	},
+
+	/// An HTTP Request timed out.
+	#[cfg(feature = "reqwest")]
+	#[error("Request to `{url}` timed out.")]
+	Timeout {
+		/// The URL of the failed request.
+		url: String,
+	},
+
+	/// An HTTP Request could not be sent, e.g. because the server could not be reached.
+	#[cfg(feature = "reqwest")]
+	#[error("Failed to send request to `{url}`: {message}")]
+	Connection {
+		/// The URL of the failed request.
+		url: String,
+
+		/// The error message for the failed request.
+		message: String,
+	},
+
 	/// An HTTP Response failed to deserialize.
 	#[cfg(feature = "reqwest")]
-	#[error("Failed to deserialize response: {0}")]
-	DeserializeResponse(String),
+	#[error("Failed to decode response from `{url}`: {serde_path}: {message}")]
+	Decode {
+		/// The URL of the request.
+		url: String,
+
+		/// The beginning of the response body.
+		body_snippet: String,
+
+		/// The entire response body, for logging.
+		body: String,
+
+		/// The path to the value which failed to deserialize (e.g. `[3].map_name`).
+		serde_path: String,
+
+		/// The error message returned by the deserializer.
+		message: String,
+	},
 
 	/// An HTTP Response was empty.
 	#[cfg(feature = "reqwest")]
@@ -70,6 +174,61 @@ pub enum Error {
 	EmptyResponse,
 }
 
+#[cfg(feature = "reqwest")]
+impl Error {
+	/// Whether the request which caused this error might succeed if it is sent again.
+	pub fn is_retryable(&self) -> bool {
+		let code = match self {
+			Self::Timeout { .. } | Self::Connection { .. } => return true,
+			Self::RateLimited { .. } => crate::http::StatusCode::TOO_MANY_REQUESTS,
+			Self::Http { code: Some(code), .. } => *code,
+			_ => return false,
+		};
+
+		crate::http::RetryPolicy::is_retryable(code)
+	}
+
+	/// Whether this error was caused by a resource that does not exist, either because the API
+	/// returned a 404 or an empty response.
+	pub fn is_not_found(&self) -> bool {
+		matches!(self, Self::NotFound { .. } | Self::EmptyResponse)
+	}
+
+	/// How long the API asked us to wait before sending another request, if it did.
+	pub fn retry_after(&self) -> Option<std::time::Duration> {
+		match self {
+			Self::RateLimited { retry_after, .. } => *retry_after,
+			_ => None,
+		}
+	}
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for Error {
+	fn from(err: reqwest::Error) -> Self {
+		let url = err
+			.url()
+			.map(ToString::to_string)
+			.unwrap_or_default();
+
+		match err.status() {
+			Some(reqwest::StatusCode::NO_CONTENT) => Self::EmptyResponse,
+			Some(reqwest::StatusCode::NOT_FOUND) => Self::NotFound { url },
+			Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) => {
+				Self::Unauthorized { url }
+			}
+			Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => {
+				Self::RateLimited { url, retry_after: None }
+			}
+			_ if err.is_timeout() => Self::Timeout { url },
+			None if err.is_connect() || err.is_request() => {
+				Self::Connection { url, message: err.to_string() }
+			}
+			code => Self::Http { code, message: err.to_string() },
+		}
+	}
+}
+
 /// Early return with the given [`enum@Error`] variant.
 #[macro_export]
 macro_rules! yeet {
@@ -1,8 +1,12 @@
 //! `/bans` endpoint
 
 use {
-	super::API_URL,
-	crate::{http, http::append_pairs, yeet, Result, SteamID},
+	super::GlobalApi,
+	crate::{
+		http::{self, append_pairs, Pagination},
+		yeet, Result, SteamID,
+	},
+	futures::Stream,
 	reqwest::Url,
 	serde::{Deserialize, Serialize},
 	std::fmt::Display,
@@ -107,27 +111,86 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/bans` route
-///
-/// Fetches the ban data for the given `params`.
+impl Params {
+	/// The URL for `/bans`, including the list parameters which are skipped when serializing
+	/// the rest of the query string.
+	pub(crate) fn url(&self, base_url: &str) -> Url {
+		let mut url = Url::parse(&format!("{base_url}/bans")).expect("This is a valid URL.");
+
+		append_pairs!(&mut url, &self.ban_types, "ban_types");
+
+		url
+	}
+}
+
+/// A new ban to submit to the API.
 ///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_bans_with(params: &Params, client: &http::Client) -> Result<Vec<Ban>> {
-	let mut url = Url::parse(&format!("{API_URL}/bans")).expect("This is a valid URL.");
-
-	append_pairs!(&mut url, &params.ban_types, "ban_types");
-
-	let bans = http::get! {
-		url = url;
-		params = params;
-		deserialize = Vec<Ban>;
-		client = client;
-	}?;
-
-	if bans.is_empty() {
-		yeet!(EmptyResponse);
+/// The server issuing the ban is determined by the API key used to submit it.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewBan {
+	pub steam_id: SteamID,
+	pub ban_type: BanType,
+	pub notes: String,
+	pub stats: String,
+	pub ip: Option<String>,
+}
+
+impl GlobalApi {
+	/// `/bans` route
+	///
+	/// Fetches the ban data for the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_bans_with(&self, params: &Params) -> Result<Vec<Ban>> {
+		let bans = http::get! {
+			url = params.url(self.base_url());
+			params = params;
+			deserialize = Vec<Ban>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if bans.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(bans)
+	}
+
+	/// `/bans` route
+	///
+	/// Same as [`Self::get_bans_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] bans, starting at `params.offset`, until there are no more
+	/// bans left. `params.limit` is ignored.
+	pub fn stream_bans_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Ban>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_bans_with(&params).await }
+		})
 	}
 
-	Ok(bans)
+	/// `POST /bans` route
+	///
+	/// Bans a player. This requires an API key.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn create_ban(&self, ban: &NewBan) -> Result<Ban> {
+		http::request! {
+			method = POST;
+			url = format!("{}/bans", self.base_url());
+			body = json(ban);
+			deserialize = Ban;
+			client = self.client();
+			rate_limiter = self.rate_limiter();
+		}
+	}
 }
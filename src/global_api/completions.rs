@@ -0,0 +1,113 @@
+//! Completion statistics computed from GlobalAPI data.
+//!
+//! The GlobalAPI has no endpoint for this, so [`completion`] computes the same numbers
+//! [KZ:GO](crate::kzgo_api)'s `/completions/:mode` endpoint returns from a player's personal
+//! bests, the global map list and the record filters.
+
+use {
+	super::{Map, Record, RecordFilter},
+	crate::{completion::CompletionStats, Mode, Runtype, Tier},
+	std::collections::{HashMap, HashSet},
+};
+
+/// The tickrate completions are counted for.
+pub const TICKRATE: u8 = 128;
+
+/// Completed and possible courses for a single mode.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Completion {
+	/// The courses the player completed.
+	pub completed: CompletionStats,
+
+	/// All courses that can be completed.
+	pub possible: CompletionStats,
+}
+
+impl Completion {
+	/// The percentage (`0..=100`) of possible courses that were completed for the given
+	/// `runtype`, either for a single `tier` or across all tiers.
+	pub fn percentage(&self, runtype: Runtype, tier: Option<Tier>) -> f64 {
+		let (completed, possible) =
+			(self.completed.runtype(runtype), self.possible.runtype(runtype));
+
+		let (completed, possible) = match tier {
+			Some(tier) => (completed.tier(tier), possible.tier(tier)),
+			None => (completed.total, possible.total),
+		};
+
+		if possible == 0 {
+			return 0.0;
+		}
+
+		f64::from(completed) / f64::from(possible) * 100.0
+	}
+}
+
+/// Counts the completed and possible main courses for the given `mode`.
+///
+/// - `records` are the player's personal bests, e.g. from
+///   [`GlobalApi::get_records_with`](super::GlobalApi::get_records_with). Records for other
+///   modes or bonuses are ignored.
+/// - `maps` determine the [`Tier`] of each course, e.g. from
+///   [`GlobalApi::get_maps`](super::GlobalApi::get_maps).
+/// - `filters` determine which courses can be completed for which runtype, e.g. from
+///   [`GlobalApi::get_filters_with`](super::GlobalApi::get_filters_with).
+///
+/// Like on KZ:GO, PRO runs also count as TP completions.
+pub fn completion(
+	mode: Mode,
+	records: &[Record],
+	maps: &[Map],
+	filters: &[RecordFilter],
+) -> Completion {
+	let tiers = maps
+		.iter()
+		.map(|map| (map.id, map.difficulty))
+		.collect::<HashMap<_, _>>();
+
+	let mut possible = CompletionStats::new(mode);
+	let mut courses = HashSet::new();
+
+	for filter in filters {
+		if filter.mode != mode || filter.stage != 0 || filter.tickrate != TICKRATE {
+			continue;
+		}
+
+		let Some(&tier) = tiers.get(&filter.map_id) else {
+			continue;
+		};
+
+		if courses.insert((filter.map_id, filter.runtype)) {
+			possible.runtype_mut(filter.runtype).add(tier);
+		}
+	}
+
+	let mut completed = CompletionStats::new(mode);
+	let mut completions = HashSet::new();
+
+	for record in records {
+		if record.mode != mode || record.stage != 0 {
+			continue;
+		}
+
+		let runtypes = match record.runtype() {
+			Runtype::Pro => &[Runtype::Pro, Runtype::TP][..],
+			Runtype::TP => &[Runtype::TP][..],
+		};
+
+		for &runtype in runtypes {
+			if !courses.contains(&(record.map_id, runtype))
+				|| !completions.insert((record.map_id, runtype))
+			{
+				continue;
+			}
+
+			completed
+				.runtype_mut(runtype)
+				.add(tiers[&record.map_id]);
+		}
+	}
+
+	Completion { completed, possible }
+}
@@ -3,8 +3,12 @@
 //! NOTE: `/record_filters/distributions` is not supported because I have no idea what it does.
 
 use {
-	super::API_URL,
-	crate::{http, http::append_pairs, yeet, Mode, Result, Runtype, SteamID},
+	super::GlobalApi,
+	crate::{
+		http::{self, append_pairs, Pagination},
+		yeet, Mode, Result, Runtype, SteamID,
+	},
+	futures::Stream,
 	reqwest::Url,
 	serde::{Deserialize, Serialize},
 };
@@ -69,30 +73,63 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/record_filters` route
-///
-/// Fetches the record filters for the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_filters_with(params: &Params, client: &http::Client) -> Result<Vec<RecordFilter>> {
-	let mut url = Url::parse(&format!("{API_URL}/record_filters")).expect("This is a valid URL.");
-
-	append_pairs!(&mut url, &params.ids, "ids");
-	append_pairs!(&mut url, &params.map_ids, "map_ids");
-	append_pairs!(&mut url, &params.stages, "stages");
-	append_pairs!(&mut url, &params.modes, "modes");
-
-	let bans = http::get! {
-		url = url;
-		params = params;
-		deserialize = Vec<RecordFilter>;
-		client = client;
-	}?;
-
-	if bans.is_empty() {
-		yeet!(EmptyResponse);
+impl Params {
+	/// The URL for `/record_filters`, including the list parameters which are skipped when
+	/// serializing the rest of the query string.
+	pub(crate) fn url(&self, base_url: &str) -> Url {
+		let mut url =
+			Url::parse(&format!("{base_url}/record_filters")).expect("This is a valid URL.");
+
+		append_pairs!(&mut url, &self.ids, "ids");
+		append_pairs!(&mut url, &self.map_ids, "map_ids");
+		append_pairs!(&mut url, &self.stages, "stages");
+		append_pairs!(&mut url, &self.modes, "modes");
+
+		url
+	}
+}
+
+impl GlobalApi {
+	/// `/record_filters` route
+	///
+	/// Fetches the record filters for the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_filters_with(&self, params: &Params) -> Result<Vec<RecordFilter>> {
+		let filters = http::get! {
+			url = params.url(self.base_url());
+			params = params;
+			deserialize = Vec<RecordFilter>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			cache = self.cache(), "/record_filters", std::time::Duration::from_secs(60 * 60);
+			in_flight = self.in_flight();
+		}?;
+
+		if filters.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(filters)
 	}
 
-	Ok(bans)
+	/// `/record_filters` route
+	///
+	/// Same as [`Self::get_filters_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] filters, starting at `params.offset`, until there are no more
+	/// filters left. `params.limit` is ignored.
+	pub fn stream_filters_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<RecordFilter>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_filters_with(&params).await }
+		})
+	}
 }
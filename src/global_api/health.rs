@@ -3,6 +3,7 @@
 //! This module gives programmatic access to this endpoint.
 
 use {
+	super::GlobalApi,
 	crate::{http, Result},
 	serde::{Deserialize, Serialize},
 };
@@ -20,24 +21,36 @@ pub struct Health {
 	pub fast: u8,
 }
 
-/// Fetches a summary for the latest 10 health checks.
-pub async fn healthcheck(client: &http::Client) -> Result<Health> {
-	let response = http::get! {
-		url = URL;
-		deserialize = Response;
-		client = client;
-	}?;
+impl GlobalApi {
+	/// Fetches a summary for the latest 10 health checks.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn healthcheck(&self) -> Result<Health> {
+		let response = http::get! {
+			url = self.health_url().expect("GlobalAPI always has a health URL.");
+			deserialize = Response;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
 
-	let (successful, fast) =
-		response.results[..10]
-			.iter()
-			.fold((0, 0), |(success, fast), result| {
-				let success = result.condition_results[0].success as u8 + success;
-				let fast = result.condition_results[1].success as u8 + fast;
-				(success, fast)
-			});
+		Ok(Health::from(response))
+	}
+}
+
+impl From<Response> for Health {
+	fn from(response: Response) -> Self {
+		let (successful, fast) =
+			response.results[..10]
+				.iter()
+				.fold((0, 0), |(success, fast), result| {
+					let success = result.condition_results[0].success as u8 + success;
+					let fast = result.condition_results[1].success as u8 + fast;
+					(success, fast)
+				});
 
-	Ok(Health { successful, fast })
+		Self { successful, fast }
+	}
 }
 
 #[allow(missing_docs)]
@@ -0,0 +1,210 @@
+//! `/jumpstats` endpoints
+//!
+//! Covered:
+//! - `/jumpstats`
+//! - `/jumpstats/:jump_type/top`
+//! - `POST /jumpstats`
+
+use {
+	super::GlobalApi,
+	crate::{
+		http::{self, Pagination},
+		yeet, JumpType, Result, SteamID,
+	},
+	futures::Stream,
+	serde::{Deserialize, Serialize},
+};
+
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Jumpstat {
+	pub id: u32,
+	pub server_id: u16,
+	pub steam_id: SteamID,
+	pub player_name: String,
+	pub jump_type: JumpType,
+	pub distance: f64,
+	pub tickrate: u8,
+	pub msl_count: u32,
+	pub strafe_count: u32,
+	pub is_crouch_bind: bool,
+	pub is_forward_bind: bool,
+	pub is_crouch_boost: bool,
+
+	#[cfg(feature = "chrono")]
+	#[serde(
+		serialize_with = "crate::serde::chrono::serialize_date",
+		deserialize_with = "crate::serde::chrono::deserialize_date"
+	)]
+	pub created_on: chrono::DateTime<chrono::Utc>,
+
+	#[cfg(not(feature = "chrono"))]
+	pub created_on: String,
+}
+
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Params {
+	pub id: Option<u32>,
+	pub server_id: Option<u16>,
+	pub steam_id: Option<SteamID>,
+
+	#[serde(rename = "jumptype")]
+	pub jump_type: Option<JumpType>,
+
+	pub greater_than_distance: Option<f64>,
+	pub less_than_distance: Option<f64>,
+	pub is_msl: Option<bool>,
+	pub is_crouch_bind: Option<bool>,
+	pub is_forward_bind: Option<bool>,
+	pub is_crouch_boost: Option<bool>,
+
+	#[cfg(feature = "chrono")]
+	#[serde(
+		serialize_with = "crate::serde::chrono::serialize_date_opt",
+		deserialize_with = "crate::serde::chrono::deserialize_date_opt"
+	)]
+	pub created_since: Option<chrono::DateTime<chrono::Utc>>,
+
+	#[cfg(not(feature = "chrono"))]
+	pub created_since: Option<String>,
+
+	pub offset: Option<u32>,
+	pub limit: Option<u32>,
+}
+
+/// Parameters for [`GlobalApi::get_top_jumpstats_with`].
+#[allow(missing_docs)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopParams {
+	pub steam_id: Option<SteamID>,
+	pub greater_than_distance: Option<f64>,
+	pub less_than_distance: Option<f64>,
+	pub is_crouch_bind: Option<bool>,
+	pub is_forward_bind: Option<bool>,
+	pub is_crouch_boost: Option<bool>,
+	pub limit: Option<u32>,
+}
+
+/// A new jumpstat to submit to the API.
+///
+/// The server the jump was done on is determined by the API key used to submit it.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewJumpstat {
+	pub steam_id: SteamID,
+	pub jump_type: JumpType,
+	pub distance: f64,
+	pub tickrate: u8,
+	pub msl_count: u32,
+	pub strafe_count: u32,
+	pub is_crouch_bind: bool,
+	pub is_forward_bind: bool,
+	pub is_crouch_boost: bool,
+
+	/// Additional information about the jump as recorded by the server.
+	pub json_jump_info: String,
+}
+
+impl GlobalApi {
+	/// `/jumpstats` route
+	///
+	/// Fetches jumpstats with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_jumpstats_with(&self, params: &Params) -> Result<Vec<Jumpstat>> {
+		let jumpstats = http::get! {
+			url = format!("{}/jumpstats", self.base_url());
+			params = params;
+			deserialize = Vec<Jumpstat>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if jumpstats.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(jumpstats)
+	}
+
+	/// `/jumpstats` route
+	///
+	/// Same as [`Self::get_jumpstats_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] jumpstats, starting at `params.offset`, until there are no more
+	/// jumpstats left. `params.limit` is ignored.
+	pub fn stream_jumpstats_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Jumpstat>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_jumpstats_with(&params).await }
+		})
+	}
+
+	/// `/jumpstats/:jump_type/top` route
+	///
+	/// Fetches the longest jumps of the given `jump_type`, with only the best jump of each player.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_top_jumpstats_with(
+		&self,
+		jump_type: JumpType,
+		params: &TopParams,
+	) -> Result<Vec<Jumpstat>> {
+		let jumpstats = http::get! {
+			url = format!("{}/jumpstats/{}/top", self.base_url(), jump_type.api());
+			params = params;
+			deserialize = Vec<Jumpstat>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if jumpstats.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(jumpstats)
+	}
+
+	/// `/jumpstats/:jump_type/top` route
+	///
+	/// Fetches the top 100 jumps of the given `jump_type` without binds.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_top_jumpstats(&self, jump_type: JumpType) -> Result<Vec<Jumpstat>> {
+		let params = TopParams {
+			is_crouch_bind: Some(false),
+			is_forward_bind: Some(false),
+			is_crouch_boost: Some(false),
+			limit: Some(100),
+			..Default::default()
+		};
+
+		self.get_top_jumpstats_with(jump_type, &params).await
+	}
+
+	/// `POST /jumpstats` route
+	///
+	/// Submits a new jumpstat. This requires an API key.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn create_jumpstat(&self, jumpstat: &NewJumpstat) -> Result<Jumpstat> {
+		http::request! {
+			method = POST;
+			url = format!("{}/jumpstats", self.base_url());
+			body = json(jumpstat);
+			deserialize = Jumpstat;
+			client = self.client();
+			rate_limiter = self.rate_limiter();
+		}
+	}
+}
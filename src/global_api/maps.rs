@@ -7,8 +7,12 @@
 //! NOTE: `/maps/:map_id` seems to be broken.
 
 use {
-	super::API_URL,
-	crate::{http, yeet, Result, SteamID, Tier},
+	super::{GlobalApi, API_URL},
+	crate::{
+		http::{self, Pagination},
+		yeet, Result, SteamID, Tier,
+	},
+	futures::{Stream, TryStreamExt},
 	serde::{Deserialize, Serialize},
 };
 
@@ -77,12 +81,22 @@ impl Map {
 
 	/// Returns a link to fetch this map from the GlobalAPI.
 	pub fn api(&self) -> String {
-		format!("{API_URL}/maps/name/{}", self.name)
+		self.api_with_base_url(API_URL)
+	}
+
+	/// Same as [`Self::api`] but with a custom base URL instead of [`API_URL`].
+	pub fn api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/name/{}", self.name)
 	}
 
 	/// Returns a link to fetch this map from DawnAPI.
 	pub fn dawn_api(&self) -> String {
-		format!("https://dawn.sh/api/kz/maps/{}", self.id)
+		self.dawn_api_with_base_url("https://dawn.sh/api/kz")
+	}
+
+	/// Same as [`Self::dawn_api`] but with a custom base URL.
+	pub fn dawn_api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/{}", self.id)
 	}
 
 	/// Returns a link to the map's KZ:GO page.
@@ -125,51 +139,86 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/maps` route
-///
-/// Fetches maps with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maps_with(params: &Params, client: &http::Client) -> Result<Vec<Map>> {
-	let maps = http::get! {
-		url = format!("{API_URL}/maps");
-		params = params;
-		deserialize = Vec<Map>;
-		client = client;
-	}?;
-
-	if maps.is_empty() {
-		yeet!(EmptyResponse);
+impl GlobalApi {
+	/// `/maps` route
+	///
+	/// Fetches maps with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maps_with(&self, params: &Params) -> Result<Vec<Map>> {
+		let maps = http::get! {
+			url = format!("{}/maps", self.base_url());
+			params = params;
+			deserialize = Vec<Map>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			cache = self.cache(), "/maps", std::time::Duration::from_secs(60 * 60);
+			in_flight = self.in_flight();
+		}?;
+
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(maps)
 	}
 
-	Ok(maps)
-}
-
-/// `/maps` route
-///
-/// Fetches maps with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maps(global: bool, client: &http::Client) -> Result<Vec<Map>> {
-	let params = Params { is_validated: Some(global), limit: Some(9999), ..Default::default() };
+	/// `/maps` route
+	///
+	/// Same as [`Self::get_maps_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] maps, starting at `params.offset`, until there are no more
+	/// maps left. `params.limit` is ignored.
+	pub fn stream_maps_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Map>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_maps_with(&params).await }
+		})
+	}
 
-	get_maps_with(&params, client).await
-}
+	/// `/maps` route
+	///
+	/// Fetches maps with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maps(&self, global: bool) -> Result<Vec<Map>> {
+		let params = Params { is_validated: Some(global), ..Default::default() };
+		let maps = self
+			.stream_maps_with(&params, Pagination::new().page_size(1000))
+			.try_collect::<Vec<_>>()
+			.await?;
+
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(maps)
+	}
 
-/// `/maps/name/:map_name` route
-///
-/// Fetches a single map with the given `name`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_map(name: &str, client: &http::Client) -> Result<Map> {
-	let map = http::get! {
-		url = format!("{API_URL}/maps/{name}");
-		deserialize = Map;
-		client = client;
-	}?;
-
-	Ok(map)
+	/// `/maps/name/:map_name` route
+	///
+	/// Fetches a single map with the given `name`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_map(&self, name: &str) -> Result<Map> {
+		let map = http::get! {
+			url = format!("{}/maps/{name}", self.base_url());
+			deserialize = Map;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		Ok(map)
+	}
 }
@@ -0,0 +1,813 @@
+//! An in-process mock of the GlobalAPI.
+//!
+//! [`MockServer`] serves [`Fixtures`] over HTTP on a random local port and understands the same
+//! query parameters the `Params` structs in [`global_api`](super) send. This makes it possible to
+//! test code using [`GlobalApi`] without network access.
+//!
+//! ```
+//! use gokz_rs::global_api::mock::MockServer;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> gokz_rs::Result<()> {
+//! let mock_server = MockServer::start()?;
+//! let global_api = mock_server.client();
+//!
+//! let record = global_api.get_record(14202658).await?;
+//! assert_eq!(record.player_name, "AlphaKeks");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Date based filters (e.g. `created_since`) and `/bans?is_expired` are ignored. If no `limit`
+//! is given, all matching items are returned.
+//!
+//! Write endpoints (`POST /records`, `POST /records/:record_id/replay`, `POST /bans` and
+//! `POST /jumpstats`) require an `X-ApiKey` header and respond with made-up ids; submitted data
+//! is not stored.
+
+use {
+	super::{
+		bans::NewBan,
+		jumpstats::{Jumpstat, NewJumpstat},
+		records::{world_records::RecordHolder, NewRecord, RecordSubmission},
+		Ban, GlobalApi, Health, Map, Player, Record, RecordFilter, Server,
+	},
+	crate::{
+		replay::{Format, Replay, ReplayKind},
+		http, JumpType, Mode, Result, Runtype, SteamID, Tier,
+	},
+	axum::{
+		extract::{Multipart, Path, Query, State},
+		http::{HeaderMap, StatusCode},
+		routing::{get, post},
+		Json, Router,
+	},
+	serde_json::{json, Value as JsonValue},
+	std::{
+		collections::{HashMap, HashSet},
+		net::SocketAddr,
+		str::FromStr,
+		sync::Arc,
+	},
+};
+
+/// The data served by a [`MockServer`].
+///
+/// [`Fixtures::default`] contains a small, fixed data set covering all endpoints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixtures {
+	/// Records served by `/records/*`.
+	///
+	/// `/records/top` only returns the fastest record of each player.
+	pub records: Vec<Record>,
+
+	/// Maps served by `/maps/*`.
+	pub maps: Vec<Map>,
+
+	/// Players served by `/players/*`.
+	pub players: Vec<Player>,
+
+	/// Servers served by `/servers/*`.
+	pub servers: Vec<Server>,
+
+	/// Bans served by `/bans`.
+	pub bans: Vec<Ban>,
+
+	/// Jumpstats served by `/jumpstats/*`.
+	///
+	/// `/jumpstats/:jump_type/top` only returns the longest jump of each player.
+	pub jumpstats: Vec<Jumpstat>,
+
+	/// Record filters served by `/record_filters`.
+	pub filters: Vec<RecordFilter>,
+
+	/// The summary served by the health endpoint.
+	pub health: Health,
+}
+
+impl Default for Fixtures {
+	fn default() -> Self {
+		Self {
+			records: serde_json::from_str(include_str!("fixtures/records.json"))
+				.expect("Bundled records are valid."),
+			maps: serde_json::from_str(include_str!("fixtures/maps.json"))
+				.expect("Bundled maps are valid."),
+			players: serde_json::from_str(include_str!("fixtures/players.json"))
+				.expect("Bundled players are valid."),
+			servers: serde_json::from_str(include_str!("fixtures/servers.json"))
+				.expect("Bundled servers are valid."),
+			bans: serde_json::from_str(include_str!("fixtures/bans.json"))
+				.expect("Bundled bans are valid."),
+			jumpstats: serde_json::from_str(include_str!("fixtures/jumpstats.json"))
+				.expect("Bundled jumpstats are valid."),
+			filters: serde_json::from_str(include_str!("fixtures/record_filters.json"))
+				.expect("Bundled record filters are valid."),
+			health: Health { successful: 10, fast: 10 },
+		}
+	}
+}
+
+/// A mock GlobalAPI running on its own thread.
+///
+/// The server shuts down when this value is dropped.
+#[derive(Debug)]
+pub struct MockServer {
+	server: http::mock::Server,
+}
+
+impl MockServer {
+	/// Starts a new server with the default [`Fixtures`].
+	pub fn start() -> Result<Self> {
+		Self::start_with(Fixtures::default())
+	}
+
+	/// Starts a new server with the given `fixtures`.
+	pub fn start_with(fixtures: Fixtures) -> Result<Self> {
+		Ok(Self { server: http::mock::Server::start(router(fixtures))? })
+	}
+
+	/// The address the server is listening on.
+	pub const fn addr(&self) -> SocketAddr {
+		self.server.addr()
+	}
+
+	/// The base URL to use for a [`GlobalApi`] talking to this server.
+	pub fn base_url(&self) -> String {
+		format!("http://{}", self.addr())
+	}
+
+	/// The URL of this server's health endpoint.
+	pub fn health_url(&self) -> String {
+		format!("http://{}/health", self.addr())
+	}
+
+	/// Returns a [`GlobalApi`] talking to this server.
+	pub fn client(&self) -> GlobalApi {
+		GlobalApi::builder()
+			.base_url(self.base_url())
+			.health_url(self.health_url())
+			.build()
+			.expect("The mock server's URLs are valid.")
+	}
+}
+
+type Fixture = State<Arc<Fixtures>>;
+type Response<T> = std::result::Result<Json<T>, StatusCode>;
+
+fn router(fixtures: Fixtures) -> Router {
+	Router::new()
+		.route("/health", get(health))
+		.route("/records", post(submit_record))
+		.route("/records/top", get(records_top))
+		.route("/records/top/world_records", get(world_records))
+		.route("/records/place/:record_id", get(place))
+		.route("/records/replay/:replay_id", get(replay))
+		.route("/records/:record_id", get(record))
+		.route("/records/:record_id/replay", post(upload_replay))
+		.route("/maps", get(maps))
+		.route("/maps/:map", get(map))
+		.route("/maps/name/:map", get(map))
+		.route("/players", get(players))
+		.route("/players/steamid/:steam_id", get(player))
+		.route("/servers", get(servers))
+		.route("/servers/:server", get(server))
+		.route("/servers/name/:server", get(server))
+		.route("/bans", get(bans).post(create_ban))
+		.route("/jumpstats", get(jumpstats).post(create_jumpstat))
+		.route("/jumpstats/:jump_type/top", get(top_jumpstats))
+		.route("/record_filters", get(filters))
+		.with_state(Arc::new(fixtures))
+}
+
+/// Write endpoints reject requests without an `X-ApiKey` header.
+fn authorize(headers: &HeaderMap) -> std::result::Result<(), StatusCode> {
+	headers
+		.contains_key("x-apikey")
+		.then_some(())
+		.ok_or(StatusCode::UNAUTHORIZED)
+}
+
+/// Query parameters as sent by the `Params` structs.
+///
+/// Lists are sent as repeated keys, so this can't be deserialized into a map.
+struct Params(Vec<(String, String)>);
+
+impl Params {
+	/// Parses the first value for `key`.
+	fn get<T: FromStr>(&self, key: &str) -> std::result::Result<Option<T>, StatusCode> {
+		self.0
+			.iter()
+			.find(|(k, _)| k == key)
+			.map(|(_, value)| value.parse().map_err(|_| StatusCode::BAD_REQUEST))
+			.transpose()
+	}
+
+	/// Parses all values for `key`. Returns [`None`] if `key` is not present at all.
+	fn all<T: FromStr>(&self, key: &str) -> std::result::Result<Option<Vec<T>>, StatusCode> {
+		let values = self
+			.0
+			.iter()
+			.filter(|(k, _)| k == key)
+			.map(|(_, value)| value.parse().map_err(|_| StatusCode::BAD_REQUEST))
+			.collect::<std::result::Result<Vec<T>, _>>()?;
+
+		Ok((!values.is_empty()).then_some(values))
+	}
+
+	/// Parses `has_teleports`, which is sent as a boolean.
+	fn runtype(&self) -> std::result::Result<Option<Runtype>, StatusCode> {
+		Ok(self
+			.get::<bool>("has_teleports")?
+			.map(Runtype::from))
+	}
+
+	/// Applies `offset` and `limit` to the given items.
+	fn paginate<T>(
+		&self,
+		items: impl IntoIterator<Item = T>,
+	) -> std::result::Result<Vec<T>, StatusCode> {
+		let offset = self.get::<usize>("offset")?.unwrap_or(0);
+		let limit = self.get::<usize>("limit")?.unwrap_or(usize::MAX);
+
+		Ok(items
+			.into_iter()
+			.skip(offset)
+			.take(limit)
+			.collect())
+	}
+}
+
+/// `true` if `filter` is unset or equal to `value`.
+fn matches<T: PartialEq>(filter: &Option<T>, value: &T) -> bool {
+	filter
+		.as_ref()
+		.is_none_or(|filter| filter == value)
+}
+
+/// `true` if `filter` is unset or contains `value`.
+fn contains<T: PartialEq>(filter: &Option<Vec<T>>, value: &T) -> bool {
+	filter
+		.as_ref()
+		.is_none_or(|filter| filter.contains(value))
+}
+
+/// `true` if `filter` is unset or equal to `value`, ignoring ASCII case.
+fn matches_name(filter: &Option<String>, value: &str) -> bool {
+	filter
+		.as_ref()
+		.is_none_or(|filter| filter.eq_ignore_ascii_case(value))
+}
+
+/// Sorts `records` by time and only keeps the fastest record of each player per course.
+fn top_records<'a>(records: impl Iterator<Item = &'a Record>) -> Vec<&'a Record> {
+	let mut records = records.collect::<Vec<_>>();
+	records.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+	let mut seen = HashSet::new();
+	records.retain(|record| {
+		seen.insert((record.steam_id, record.map_id, record.stage, record.mode, record.runtype()))
+	});
+
+	records
+}
+
+/// Same as [`top_records`], but only keeps the fastest record of each player per course across
+/// both runtypes.
+fn top_records_overall<'a>(records: impl Iterator<Item = &'a Record>) -> Vec<&'a Record> {
+	let mut records = records.collect::<Vec<_>>();
+	records.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+	let mut seen = HashSet::new();
+	records.retain(|record| seen.insert((record.steam_id, record.map_id, record.stage, record.mode)));
+
+	records
+}
+
+async fn health(State(fixtures): Fixture) -> Json<JsonValue> {
+	let Health { successful, fast } = fixtures.health;
+	let results = (0..10)
+		.map(|i| {
+			json!({
+				"conditionResults": [
+					{ "success": i < successful },
+					{ "success": i < fast }
+				]
+			})
+		})
+		.collect::<Vec<_>>();
+
+	Json(json!({ "results": results }))
+}
+
+async fn records_top(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Record>> {
+	let params = Params(params);
+	let steam_id = params.get::<SteamID>("steam_id")?;
+	let player_name = params.get::<String>("player_name")?;
+	let map_id = params.get::<u16>("map_id")?;
+	let map_name = params.get::<String>("map_name")?;
+	let stage = params.get::<u8>("stage")?;
+	let mode = params.get::<Mode>("modes_list_string")?;
+	let tickrate = params.get::<u8>("tickrate")?;
+	let overall = params.get::<bool>("overall")?.unwrap_or(false);
+
+	// The overall leaderboard combines PRO and TP runs.
+	let runtype = match overall {
+		true => None,
+		false => params.runtype()?,
+	};
+
+	let records = fixtures.records.iter().filter(|record| {
+		matches(&steam_id, &record.steam_id)
+			&& matches_name(&player_name, &record.player_name)
+			&& matches(&map_id, &record.map_id)
+			&& matches_name(&map_name, &record.map_name)
+			&& matches(&stage, &record.stage)
+			&& matches(&mode, &record.mode)
+			&& matches(&tickrate, &record.tickrate)
+			&& matches(&runtype, &record.runtype())
+	});
+
+	let records = match overall {
+		true => top_records_overall(records),
+		false => top_records(records),
+	};
+
+	Ok(Json(params.paginate(records.into_iter().cloned())?))
+}
+
+async fn world_records(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<RecordHolder>> {
+	let params = Params(params);
+	let map_ids = params.all::<u16>("map_ids")?;
+	let stages = params.all::<u8>("stages")?;
+	let modes = params
+		.all::<u8>("mode_ids")?
+		.map(|modes| {
+			modes
+				.into_iter()
+				.map(Mode::try_from)
+				.collect::<Result<Vec<_>>>()
+		})
+		.transpose()
+		.map_err(|_| StatusCode::BAD_REQUEST)?;
+	let tickrates = params.all::<u8>("tickrates")?;
+	let runtype = params.runtype()?;
+
+	let records = top_records(fixtures.records.iter().filter(|record| {
+		contains(&map_ids, &record.map_id)
+			&& contains(&stages, &record.stage)
+			&& contains(&modes, &record.mode)
+			&& contains(&tickrates, &record.tickrate)
+			&& matches(&runtype, &record.runtype())
+	}));
+
+	let mut courses = HashSet::new();
+	let mut holders = Vec::<RecordHolder>::new();
+	let mut indices = HashMap::new();
+
+	for record in records {
+		if !courses.insert((record.map_id, record.stage, record.mode, record.runtype())) {
+			continue;
+		}
+
+		let idx = *indices.entry(record.steam_id).or_insert_with(|| {
+			holders.push(RecordHolder {
+				count: 0,
+				steam_id: record.steam_id,
+				player_name: record.player_name.clone(),
+			});
+
+			holders.len() - 1
+		});
+
+		holders[idx].count += 1;
+	}
+
+	holders.sort_by_key(|holder| std::cmp::Reverse(holder.count));
+
+	Ok(Json(params.paginate(holders)?))
+}
+
+async fn place(State(fixtures): Fixture, Path(record_id): Path<u32>) -> Response<u32> {
+	let record = fixtures
+		.records
+		.iter()
+		.find(|record| record.id == record_id)
+		.ok_or(StatusCode::NOT_FOUND)?;
+
+	let faster = top_records(fixtures.records.iter().filter(|other| {
+		other.map_id == record.map_id
+			&& other.stage == record.stage
+			&& other.mode == record.mode
+			&& other.runtype() == record.runtype()
+			&& other.steam_id != record.steam_id
+			&& other.time < record.time
+	}))
+	.len();
+
+	Ok(Json(faster as u32 + 1))
+}
+
+async fn record(State(fixtures): Fixture, Path(record_id): Path<u32>) -> Response<Record> {
+	fixtures
+		.records
+		.iter()
+		.find(|record| record.id == record_id)
+		.cloned()
+		.map(Json)
+		.ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Assigns the next free record id to the submitted record. Nothing is stored.
+async fn submit_record(
+	State(fixtures): Fixture,
+	headers: HeaderMap,
+	Json(record): Json<NewRecord>,
+) -> Response<RecordSubmission> {
+	authorize(&headers)?;
+
+	if record.validate().is_err() {
+		return Err(StatusCode::BAD_REQUEST);
+	}
+
+	let record_id = fixtures
+		.records
+		.iter()
+		.map(|record| record.id)
+		.max()
+		.unwrap_or(0)
+		+ 1;
+
+	let replay_id = fixtures
+		.records
+		.iter()
+		.map(|record| record.replay_id)
+		.max()
+		.unwrap_or(0)
+		+ 1;
+
+	Ok(Json(RecordSubmission { record_id, replay_id }))
+}
+
+/// Accepts a multipart form with a single `replay` part named `<record_id>.replay`, which has
+/// to contain a valid replay.
+async fn upload_replay(
+	State(fixtures): Fixture,
+	Path(record_id): Path<u32>,
+	headers: HeaderMap,
+	mut multipart: Multipart,
+) -> std::result::Result<(), StatusCode> {
+	authorize(&headers)?;
+
+	if !fixtures
+		.records
+		.iter()
+		.any(|record| record.id == record_id)
+	{
+		return Err(StatusCode::NOT_FOUND);
+	}
+
+	let part = multipart
+		.next_field()
+		.await
+		.map_err(|_| StatusCode::BAD_REQUEST)?
+		.ok_or(StatusCode::BAD_REQUEST)?;
+
+	let file_name = format!("{record_id}.replay");
+
+	if part.name() != Some("replay") || part.file_name() != Some(file_name.as_str()) {
+		return Err(StatusCode::BAD_REQUEST);
+	}
+
+	let replay = part
+		.bytes()
+		.await
+		.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+	Replay::parse(&replay).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+	Ok(())
+}
+
+/// Serves a replay without any ticks, based on the record with the given `replay_id`.
+async fn replay(
+	State(fixtures): Fixture,
+	Path(replay_id): Path<u32>,
+) -> std::result::Result<Vec<u8>, StatusCode> {
+	let record = fixtures
+		.records
+		.iter()
+		.find(|record| replay_id != 0 && record.replay_id == replay_id)
+		.ok_or(StatusCode::NOT_FOUND)?;
+
+	let replay = Replay {
+		gokz_version: String::from("3.4.2"),
+		map_name: record.map_name.clone(),
+		mode: record.mode,
+		style: 0,
+		steam_id: record.steam_id,
+		player_alias: record.player_name.clone(),
+		kind: ReplayKind::Run {
+			course: record.stage,
+			time: record.time,
+			teleports: record.teleports,
+		},
+		format: Format::V2 {
+			map_file_size: 0,
+			server_ip: 0,
+			timestamp: 0,
+			sensitivity: 1.0,
+			m_yaw: 0.022,
+			tickrate: 128.0,
+			weapon: 0,
+			knife: 0,
+		},
+		ticks: Vec::new(),
+	};
+
+	replay
+		.to_bytes()
+		.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+async fn maps(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Map>> {
+	let params = Params(params);
+	let id = params.get::<u16>("id")?;
+	let name = params.get::<String>("name")?;
+	let larger_than = params.get::<u64>("larger_than_filesize")?;
+	let smaller_than = params.get::<u64>("smaller_than_filesize")?;
+	let validated = params.get::<bool>("is_validated")?;
+	let tier = params.get::<Tier>("difficulty")?;
+
+	let maps = fixtures.maps.iter().filter(|map| {
+		matches(&id, &map.id)
+			&& matches_name(&name, &map.name)
+			&& larger_than.is_none_or(|size| map.filesize > size)
+			&& smaller_than.is_none_or(|size| map.filesize < size)
+			&& matches(&validated, &map.validated)
+			&& matches(&tier, &map.difficulty)
+	});
+
+	Ok(Json(params.paginate(maps.cloned())?))
+}
+
+async fn map(State(fixtures): Fixture, Path(map): Path<String>) -> Response<Map> {
+	let map_id = map.parse::<u16>().ok();
+
+	fixtures
+		.maps
+		.iter()
+		.find(|m| Some(m.id) == map_id || m.name.eq_ignore_ascii_case(&map))
+		.cloned()
+		.map(Json)
+		.ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn players(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Player>> {
+	let params = Params(params);
+	let name = params.get::<String>("name")?;
+	let steam_id = params.get::<SteamID>("steam_id")?;
+	let banned = params.get::<bool>("is_banned")?;
+
+	let players = fixtures.players.iter().filter(|player| {
+		matches_name(&name, &player.name)
+			&& matches(&steam_id, &player.steam_id)
+			&& matches(&banned, &player.is_banned)
+	});
+
+	Ok(Json(params.paginate(players.cloned())?))
+}
+
+async fn player(State(fixtures): Fixture, Path(steam_id): Path<String>) -> Response<Vec<Player>> {
+	let steam_id = steam_id
+		.parse::<SteamID>()
+		.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+	let players = fixtures
+		.players
+		.iter()
+		.filter(|player| player.steam_id == steam_id)
+		.cloned()
+		.collect();
+
+	Ok(Json(players))
+}
+
+async fn servers(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Server>> {
+	let params = Params(params);
+	let ids = params.all::<u16>("id")?;
+	let name = params.get::<String>("name")?;
+	let ip = params.get::<String>("ip")?;
+	let port = params.get::<u16>("port")?;
+	let owned_by = params.get::<SteamID>("owner_steamid64")?;
+
+	let servers = fixtures.servers.iter().filter(|server| {
+		contains(&ids, &server.id)
+			&& matches_name(&name, &server.name)
+			&& matches(&ip, &server.ip)
+			&& matches(&port, &server.port)
+			&& matches(&owned_by, &server.owned_by)
+	});
+
+	Ok(Json(params.paginate(servers.cloned())?))
+}
+
+async fn server(State(fixtures): Fixture, Path(server): Path<String>) -> Response<Server> {
+	let server_id = server.parse::<u16>().ok();
+
+	fixtures
+		.servers
+		.iter()
+		.find(|s| Some(s.id) == server_id || s.name.eq_ignore_ascii_case(&server))
+		.cloned()
+		.map(Json)
+		.ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn bans(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Ban>> {
+	let params = Params(params);
+	let ban_types = params.all::<String>("ban_types")?;
+	let steam_id = params.get::<SteamID>("steam_id")?;
+	let notes = params.get::<String>("notes_contains")?;
+	let stats = params.get::<String>("stats_contains")?;
+	let server_id = params.get::<u16>("server_id")?;
+
+	let bans = fixtures.bans.iter().filter(|ban| {
+		contains(&ban_types, &ban.ban_type.to_string())
+			&& matches(&steam_id, &ban.steam_id)
+			&& notes
+				.as_ref()
+				.is_none_or(|notes| ban.notes.contains(notes))
+			&& stats
+				.as_ref()
+				.is_none_or(|stats| ban.stats.contains(stats))
+			&& matches(&server_id, &ban.server_id)
+	});
+
+	Ok(Json(params.paginate(bans.cloned())?))
+}
+
+/// Echoes the submitted ban back, based on the first ban in the fixtures. Nothing is stored.
+async fn create_ban(
+	State(fixtures): Fixture,
+	headers: HeaderMap,
+	Json(new_ban): Json<NewBan>,
+) -> Response<Ban> {
+	authorize(&headers)?;
+
+	let ban = fixtures
+		.bans
+		.first()
+		.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+	Ok(Json(Ban {
+		id: ban.id + 1,
+		ban_type: new_ban.ban_type,
+		steam_id: new_ban.steam_id,
+		notes: new_ban.notes,
+		stats: new_ban.stats,
+		..ban.clone()
+	}))
+}
+
+async fn create_jumpstat(
+	State(fixtures): Fixture,
+	headers: HeaderMap,
+	Json(new_jumpstat): Json<NewJumpstat>,
+) -> Response<Jumpstat> {
+	authorize(&headers)?;
+
+	let jumpstat = fixtures
+		.jumpstats
+		.iter()
+		.max_by_key(|jumpstat| jumpstat.id)
+		.ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+	let player_name = fixtures
+		.players
+		.iter()
+		.find(|player| player.steam_id == new_jumpstat.steam_id)
+		.map_or_else(|| new_jumpstat.steam_id.to_string(), |player| player.name.clone());
+
+	Ok(Json(Jumpstat {
+		id: jumpstat.id + 1,
+		steam_id: new_jumpstat.steam_id,
+		player_name,
+		jump_type: new_jumpstat.jump_type,
+		distance: new_jumpstat.distance,
+		tickrate: new_jumpstat.tickrate,
+		msl_count: new_jumpstat.msl_count,
+		strafe_count: new_jumpstat.strafe_count,
+		is_crouch_bind: new_jumpstat.is_crouch_bind,
+		is_forward_bind: new_jumpstat.is_forward_bind,
+		is_crouch_boost: new_jumpstat.is_crouch_boost,
+		..jumpstat.clone()
+	}))
+}
+
+/// Filters shared by `/jumpstats` and `/jumpstats/:jump_type/top`.
+fn filter_jumpstats<'a>(
+	fixtures: &'a Fixtures,
+	params: &Params,
+) -> std::result::Result<impl Iterator<Item = &'a Jumpstat>, StatusCode> {
+	let steam_id = params.get::<SteamID>("steam_id")?;
+	let jump_type = params.get::<JumpType>("jumptype")?;
+	let greater_than = params.get::<f64>("greater_than_distance")?;
+	let less_than = params.get::<f64>("less_than_distance")?;
+	let is_msl = params.get::<bool>("is_msl")?;
+	let is_crouch_bind = params.get::<bool>("is_crouch_bind")?;
+	let is_forward_bind = params.get::<bool>("is_forward_bind")?;
+	let is_crouch_boost = params.get::<bool>("is_crouch_boost")?;
+
+	Ok(fixtures.jumpstats.iter().filter(move |jumpstat| {
+		matches(&steam_id, &jumpstat.steam_id)
+			&& matches(&jump_type, &jumpstat.jump_type)
+			&& greater_than.is_none_or(|distance| jumpstat.distance > distance)
+			&& less_than.is_none_or(|distance| jumpstat.distance < distance)
+			&& matches(&is_msl, &(jumpstat.msl_count > 0))
+			&& matches(&is_crouch_bind, &jumpstat.is_crouch_bind)
+			&& matches(&is_forward_bind, &jumpstat.is_forward_bind)
+			&& matches(&is_crouch_boost, &jumpstat.is_crouch_boost)
+	}))
+}
+
+async fn jumpstats(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Jumpstat>> {
+	let params = Params(params);
+	let id = params.get::<u32>("id")?;
+	let server_id = params.get::<u16>("server_id")?;
+
+	let jumpstats = filter_jumpstats(&fixtures, &params)?.filter(|jumpstat| {
+		matches(&id, &jumpstat.id) && matches(&server_id, &jumpstat.server_id)
+	});
+
+	Ok(Json(params.paginate(jumpstats.cloned())?))
+}
+
+async fn top_jumpstats(
+	State(fixtures): Fixture,
+	Path(jump_type): Path<String>,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<Jumpstat>> {
+	let jump_type = jump_type
+		.parse::<JumpType>()
+		.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+	let params = Params(params);
+	let mut jumpstats = filter_jumpstats(&fixtures, &params)?
+		.filter(|jumpstat| jumpstat.jump_type == jump_type)
+		.collect::<Vec<_>>();
+
+	jumpstats.sort_by(|a, b| b.distance.total_cmp(&a.distance));
+
+	let mut players = HashSet::new();
+	let jumpstats = jumpstats
+		.into_iter()
+		.filter(|jumpstat| players.insert(jumpstat.steam_id))
+		.cloned();
+
+	Ok(Json(params.paginate(jumpstats)?))
+}
+
+async fn filters(
+	State(fixtures): Fixture,
+	Query(params): Query<Vec<(String, String)>>,
+) -> Response<Vec<RecordFilter>> {
+	let params = Params(params);
+	let ids = params.all::<u16>("ids")?;
+	let map_ids = params.all::<u16>("map_ids")?;
+	let stages = params.all::<u8>("stages")?;
+	let modes = params.all::<Mode>("modes")?;
+	let tickrate = params.get::<u8>("tickrates")?;
+	let runtype = params.runtype()?;
+
+	let filters = fixtures.filters.iter().filter(|filter| {
+		contains(&ids, &filter.id)
+			&& contains(&map_ids, &filter.map_id)
+			&& contains(&stages, &filter.stage)
+			&& contains(&modes, &filter.mode)
+			&& matches(&tickrate, &filter.tickrate)
+			&& matches(&runtype, &filter.runtype)
+	});
+
+	Ok(Json(params.paginate(filters.cloned())?))
+}
@@ -1,5 +1,7 @@
 //! This module contains various functions and submodules covering the
 //! [GlobalAPI](https://kztimerglobal.com/swagger/index.html?urls.primaryName=V2).
+//!
+//! All endpoints are exposed as methods on [`GlobalApi`].
 
 /// The base URL for all API requests.
 pub const API_URL: &str = "https://kztimerglobal.com/api/v2";
@@ -7,27 +9,51 @@ pub const API_URL: &str = "https://kztimerglobal.com/api/v2";
 /// The URL for the API's SwaggerUI website.
 pub const SWAGGER_URL: &str = "https://kztimerglobal.com/swagger/index.html?urls.primaryName=V2";
 
+crate::http::api_client! {
+	/// A client for the [GlobalAPI](https://kztimerglobal.com/swagger/index.html?urls.primaryName=V2).
+	///
+	/// ```no_run
+	/// use gokz_rs::global_api::GlobalApi;
+	///
+	/// # async fn run() -> gokz_rs::Result<()> {
+	/// let global_api = GlobalApi::builder()
+	/// 	.user_agent("my-kz-bot")
+	/// 	.timeout(std::time::Duration::from_secs(10))
+	/// 	.build()?;
+	///
+	/// let record = global_api.get_record(14202658).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	GlobalApi, GlobalApiBuilder, API_URL, health::URL
+}
+
 pub mod health;
-pub use health::{healthcheck, Health};
+pub use health::Health;
 
 pub mod bans;
-pub use bans::{get_bans_with, Ban};
+pub use bans::Ban;
 
 pub mod maps;
-pub use maps::{get_map, get_maps, get_maps_with, Map};
+pub use maps::Map;
 
 pub mod servers;
-pub use servers::{get_server, get_servers_owned_by, get_servers_with, Server};
+pub use servers::Server;
 
 pub mod players;
-pub use players::{get_player, get_players_with, Player};
+pub use players::Player;
 
 pub mod filters;
-pub use filters::{get_filters_with, RecordFilter};
+pub use filters::RecordFilter;
 
 pub mod records;
-pub use records::{
-	get_maptop, get_pb, get_place, get_record, get_records_with, get_wr,
-	world_records::{self, get_wr_leaderboard},
-	Record,
-};
+pub use records::{world_records, Record};
+
+pub mod completions;
+pub use completions::Completion;
+
+pub mod jumpstats;
+pub use jumpstats::Jumpstat;
+
+#[cfg(feature = "mock")]
+pub mod mock;
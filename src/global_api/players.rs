@@ -7,8 +7,12 @@
 //! NOTE: `/players/steamid/:steam_id/alts` seems to be broken.
 
 use {
-	super::API_URL,
-	crate::{http, yeet, PlayerIdentifier, Result, SteamID},
+	super::{GlobalApi, API_URL},
+	crate::{
+		http::{self, Pagination},
+		yeet, PlayerIdentifier, Result, SteamID,
+	},
+	futures::Stream,
 	serde::{Deserialize, Serialize},
 };
 
@@ -30,7 +34,13 @@ impl Player {
 	/// Returns a link to fetch this player from the GlobalAPI.
 	#[inline]
 	pub fn api(&self) -> String {
-		format!("{API_URL}/players/steam_id/{}", self.steam_id)
+		self.api_with_base_url(API_URL)
+	}
+
+	/// Same as [`Self::api`] but with a custom base URL instead of [`API_URL`].
+	#[inline]
+	pub fn api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/players/steam_id/{}", self.steam_id)
 	}
 
 	/// Returns a link to the player's KZ:GO profile.
@@ -38,6 +48,13 @@ impl Player {
 	pub fn kzgo_profile(&self) -> String {
 		format!("https://kzgo.eu/players/{}", self.steam_id)
 	}
+
+	/// Fetches the player's Steam avatar.
+	///
+	/// * `api_key`: [Steam WebAPI key](https://steamcommunity.com/dev/apikey)
+	pub async fn avatar_url(&self, api_key: &str, client: &http::Client) -> Result<String> {
+		self.steam_id.avatar_url(api_key, client).await
+	}
 }
 
 #[allow(missing_docs)]
@@ -52,54 +69,80 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/players` route
-///
-/// Fetches players with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_players_with(params: &Params, client: &http::Client) -> Result<Vec<Player>> {
-	let players = http::get! {
-		url = format!("{API_URL}/players");
-		params = params;
-		deserialize = Vec<Player>;
-		client = client;
-	}?;
-
-	if players.is_empty() {
-		yeet!(EmptyResponse);
-	}
-
-	Ok(players)
-}
+impl GlobalApi {
+	/// `/players` route
+	///
+	/// Fetches players with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_players_with(&self, params: &Params) -> Result<Vec<Player>> {
+		let players = http::get! {
+			url = format!("{}/players", self.base_url());
+			params = params;
+			deserialize = Vec<Player>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
 
-/// `/players` or `/players/steamid/:steam_id` route depending on the exact input.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_player(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<Player> {
-	let (url, params) = match player.into() {
-		PlayerIdentifier::Name(name) => (format!("{API_URL}/players"), Params {
-			name: Some(name),
-			limit: Some(1),
-			..Default::default()
-		}),
-		PlayerIdentifier::SteamID(steam_id) => {
-			(format!("{API_URL}/players/steamid/{steam_id}"), Params::default())
+		if players.is_empty() {
+			yeet!(EmptyResponse);
 		}
-	};
 
-	let mut players = http::get! {
-		url = url;
-		params = &params;
-		deserialize = Vec<Player>;
-		client = client;
-	}?;
+		Ok(players)
+	}
+
+	/// `/players` route
+	///
+	/// Same as [`Self::get_players_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] players, starting at `params.offset`, until there are no more
+	/// players left. `params.limit` is ignored.
+	pub fn stream_players_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Player>> + 'a {
+		let params = params.clone();
 
-	if players.is_empty() {
-		yeet!(EmptyResponse);
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_players_with(&params).await }
+		})
 	}
 
-	Ok(players.remove(0))
+	/// `/players` or `/players/steamid/:steam_id` route depending on the exact input.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_player(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+	) -> Result<Player> {
+		let (url, params) = match player.into() {
+			PlayerIdentifier::Name(name) => (format!("{}/players", self.base_url()), Params {
+				name: Some(name),
+				limit: Some(1),
+				..Default::default()
+			}),
+			PlayerIdentifier::SteamID(steam_id) => {
+				(format!("{}/players/steamid/{steam_id}", self.base_url()), Params::default())
+			}
+		};
+
+		let mut players = http::get! {
+			url = url;
+			params = &params;
+			deserialize = Vec<Player>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if players.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(players.remove(0))
+	}
 }
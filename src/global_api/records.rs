@@ -5,10 +5,19 @@
 //! - `/records/top`
 //! - `/records/top/world_records`
 //! - `/records/place/:record_id`
+//! - `POST /records`
+//! - `POST /records/:record_id/replay`
+//! - `/records/replay/:replay_id`
 
 use {
-	super::API_URL,
-	crate::{http, yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, SteamID},
+	super::{GlobalApi, Map, RecordFilter, API_URL},
+	crate::{
+		http::{self, Pagination},
+		replay::Replay,
+		yeet, MapIdentifier, Mode, PlayerIdentifier, Result, Runtype, SteamID, Tier,
+	},
+	futures::Stream,
+	reqwest::multipart::{Form, Part},
 	serde::{Deserialize, Serialize},
 };
 
@@ -22,6 +31,7 @@ pub struct Record {
 	pub map_name: String,
 	pub stage: u8,
 	pub mode: Mode,
+	pub tickrate: u8,
 	pub time: f64,
 	pub teleports: u32,
 	pub server_id: u16,
@@ -74,7 +84,12 @@ impl Record {
 
 	/// Returns a link to fetch the map the record was done on from the GlobalAPI.
 	pub fn api(&self) -> String {
-		format!("{API_URL}/maps/name/{}", self.map_name)
+		self.api_with_base_url(API_URL)
+	}
+
+	/// Same as [`Self::api`] but with a custom base URL instead of [`API_URL`].
+	pub fn api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/maps/name/{}", self.map_name)
 	}
 
 	/// Returns a link to the KZ:GO page of the map this record was done on.
@@ -84,11 +99,16 @@ impl Record {
 
 	/// Returns a download link for the replay of this record.
 	pub fn replay_download(&self) -> Option<String> {
+		self.replay_download_with_base_url(API_URL)
+	}
+
+	/// Same as [`Self::replay_download`] but with a custom base URL instead of [`API_URL`].
+	pub fn replay_download_with_base_url(&self, base_url: &str) -> Option<String> {
 		if self.replay_id == 0 {
 			return None;
 		}
 
-		Some(format!("{API_URL}/records/replay/{}", self.replay_id))
+		Some(format!("{base_url}/records/replay/{}", self.replay_id))
 	}
 
 	/// Returns a link to watch the replay of this record online.
@@ -114,35 +134,6 @@ impl Record {
 	}
 }
 
-/// `/records/:record_id` route
-///
-/// Fetches a specific record by id.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_record(record_id: u32, client: &crate::http::Client) -> Result<Record> {
-	http::get! {
-		url = format!("{API_URL}/records/{record_id}");
-		deserialize = Record;
-		client = client;
-	}
-}
-
-/// `/records/place/:record_id` route
-///
-/// Fetches the leaderboard place for a specific record.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_place(record_id: u32, client: &crate::http::Client) -> Result<u32> {
-	#[derive(Deserialize)]
-	struct Place(u32);
-
-	let Place(place) = http::get! {
-		url = format!("{API_URL}/records/place/{record_id}");
-		deserialize = Place;
-		client = client;
-	}?;
-
-	Ok(place)
-}
-
 #[allow(missing_docs)]
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Params {
@@ -165,121 +156,349 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/records/top` route
-///
-/// Fetches records with the given `params`.
+/// A new record to submit to the API.
 ///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_records_with(
-	params: &Params,
-	client: &crate::http::Client,
-) -> Result<Vec<Record>> {
-	let records = http::get! {
-		url = format!("{API_URL}/records/top");
-		params = params;
-		deserialize = Vec<Record>;
-		client = client;
-	}?;
-
-	if records.is_empty() {
-		yeet!(EmptyResponse);
+/// [`GlobalApi::submit_record`] checks the record with [`NewRecord::validate`] before sending
+/// it.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewRecord {
+	pub steam_id: SteamID,
+	pub map_id: u16,
+	pub stage: u8,
+	pub mode: Mode,
+	pub tickrate: u8,
+	pub time: f64,
+	pub teleports: u32,
+	pub server_id: u16,
+}
+
+impl NewRecord {
+	/// The tickrates the API accepts records for.
+	pub const TICKRATES: [u8; 3] = [64, 102, 128];
+
+	/// Checks whether the API would accept this record.
+	///
+	/// The [`SteamID`] and [`Mode`] are already valid by construction; this checks the remaining
+	/// fields that can be checked without knowing the map. Any `stage` and `teleports` are
+	/// plausible on their own, see [`NewRecord::validate_for`] for checking them as well.
+	pub fn validate(&self) -> Result<()> {
+		if self.map_id == 0 {
+			yeet!(InvalidMapID(self.map_id));
+		}
+
+		if self.server_id == 0 {
+			yeet!(InvalidServerID(self.server_id));
+		}
+
+		if !Self::TICKRATES.contains(&self.tickrate) {
+			yeet!(InvalidTickrate(self.tickrate));
+		}
+
+		if !self.time.is_finite() || self.time <= 0.0 {
+			yeet!(InvalidTime(self.time));
+		}
+
+		Ok(())
 	}
 
-	Ok(records)
-}
+	/// Same as [`NewRecord::validate`], but also checks the record against the `map` it was set
+	/// on and that map's record `filters` (see [`GlobalApi::get_filters_with`]).
+	///
+	/// The API only accepts records on global maps, and only for combinations of stage, mode,
+	/// tickrate and runtype that have a [`RecordFilter`]. The map's [`Tier`] only describes the
+	/// main course, so bonuses are checked using the filters alone.
+	///
+	/// Returns the map's [`Tier`] if the record would be accepted.
+	pub fn validate_for(&self, map: &Map, filters: &[RecordFilter]) -> Result<Tier> {
+		self.validate()?;
 
-/// `/records/top` route
-///
-/// Fetches the world record for the given parameters.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_wr(
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	course: u8,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	runtype: impl Into<Runtype> + std::fmt::Debug,
-	client: &crate::http::Client,
-) -> Result<Record> {
-	let mut params = Params {
-		stage: Some(course),
-		mode: Some(mode.into()),
-		runtype: Some(runtype.into()),
-		limit: Some(1),
-		..Default::default()
-	};
-
-	match map.into() {
-		MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
-		MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
-	};
-
-	Ok(get_records_with(&params, client).await?.remove(0))
+		if map.id != self.map_id {
+			yeet!(InvalidMapID(self.map_id));
+		}
+
+		if !map.validated {
+			yeet!(NotRanked(format!("`{}`", map.name)));
+		}
+
+		let mut stage_filters = filters
+			.iter()
+			.filter(|filter| filter.map_id == self.map_id && filter.stage == self.stage)
+			.peekable();
+
+		if stage_filters.peek().is_none() {
+			yeet!(InvalidStage(self.stage));
+		}
+
+		if !stage_filters.any(|filter| {
+			filter.mode == self.mode
+				&& filter.tickrate == self.tickrate
+				&& filter.runtype == self.runtype()
+		}) {
+			yeet!(NotRanked(format!(
+				"{} {} on stage {} of `{}` at {} tick",
+				self.mode, self.runtype(), self.stage, map.name, self.tickrate
+			)));
+		}
+
+		Ok(map.difficulty)
+	}
+
+	/// Returns the [`Runtype`] for this record.
+	pub const fn runtype(&self) -> Runtype {
+		match self.teleports > 0 {
+			true => Runtype::TP,
+			false => Runtype::Pro,
+		}
+	}
 }
 
-/// `/records/top` route
-///
-/// Fetches the top `n` records for the given parameters.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maptop(
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	course: u8,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	runtype: impl Into<Runtype> + std::fmt::Debug,
-	n: usize,
-	client: &crate::http::Client,
-) -> Result<Vec<Record>> {
-	let mut params = Params {
-		stage: Some(course),
-		mode: Some(mode.into()),
-		runtype: Some(runtype.into()),
-		limit: Some(n as u32),
-		..Default::default()
-	};
-
-	match map.into() {
-		MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
-		MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
-	};
-
-	get_records_with(&params, client).await
+/// The response to [`GlobalApi::submit_record`].
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordSubmission {
+	#[serde(alias = "id")]
+	pub record_id: u32,
+	pub replay_id: u32,
 }
 
-/// `/records/top` route
-///
-/// Fetches a player's personal best on the given map.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_pb(
-	player: impl Into<PlayerIdentifier> + std::fmt::Debug,
-	map: impl Into<MapIdentifier> + std::fmt::Debug,
-	course: u8,
-	mode: impl Into<Mode> + std::fmt::Debug,
-	runtype: impl Into<Runtype> + std::fmt::Debug,
-	client: &crate::http::Client,
-) -> Result<Record> {
-	let mut params = Params {
-		stage: Some(course),
-		mode: Some(mode.into()),
-		runtype: Some(runtype.into()),
-		limit: Some(1),
-		..Default::default()
-	};
-
-	match player.into() {
-		PlayerIdentifier::SteamID(steam_id) => params.steam_id = Some(steam_id),
-		PlayerIdentifier::Name(name) => params.player_name = Some(name),
-	};
-
-	match map.into() {
-		MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
-		MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
-	};
-
-	Ok(get_records_with(&params, client).await?.remove(0))
+impl GlobalApi {
+	/// `/records/:record_id` route
+	///
+	/// Fetches a specific record by id.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_record(&self, record_id: u32) -> Result<Record> {
+		http::get! {
+			url = format!("{}/records/{record_id}", self.base_url());
+			deserialize = Record;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}
+	}
+
+	/// `/records/place/:record_id` route
+	///
+	/// Fetches the leaderboard place for a specific record.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_place(&self, record_id: u32) -> Result<u32> {
+		#[derive(Deserialize)]
+		struct Place(u32);
+
+		let Place(place) = http::get! {
+			url = format!("{}/records/place/{record_id}", self.base_url());
+			deserialize = Place;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		Ok(place)
+	}
+
+	/// `/records/top` route
+	///
+	/// Fetches records with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_records_with(&self, params: &Params) -> Result<Vec<Record>> {
+		let records = http::get! {
+			url = format!("{}/records/top", self.base_url());
+			params = params;
+			deserialize = Vec<Record>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if records.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(records)
+	}
+
+	/// `/records/top` route
+	///
+	/// Same as [`Self::get_records_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] records, starting at `params.offset`, until there are no more
+	/// records left. `params.limit` is ignored.
+	pub fn stream_records_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Record>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_records_with(&params).await }
+		})
+	}
+
+	/// `/records/top` route
+	///
+	/// Fetches the world record for the given parameters.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_wr(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		let mut params = Params {
+			stage: Some(course),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(1),
+			..Default::default()
+		};
+
+		match map.into() {
+			MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
+			MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
+		};
+
+		Ok(self.get_records_with(&params).await?.remove(0))
+	}
+
+	/// `/records/top` route
+	///
+	/// Fetches the top `n` records for the given parameters.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maptop(
+		&self,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+		n: usize,
+	) -> Result<Vec<Record>> {
+		let mut params = Params {
+			stage: Some(course),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(n as u32),
+			..Default::default()
+		};
+
+		match map.into() {
+			MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
+			MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
+		};
+
+		self.get_records_with(&params).await
+	}
+
+	/// `/records/top` route
+	///
+	/// Fetches a player's personal best on the given map.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_pb(
+		&self,
+		player: impl Into<PlayerIdentifier> + std::fmt::Debug,
+		map: impl Into<MapIdentifier> + std::fmt::Debug,
+		course: u8,
+		mode: impl Into<Mode> + std::fmt::Debug,
+		runtype: impl Into<Runtype> + std::fmt::Debug,
+	) -> Result<Record> {
+		let mut params = Params {
+			stage: Some(course),
+			mode: Some(mode.into()),
+			runtype: Some(runtype.into()),
+			limit: Some(1),
+			..Default::default()
+		};
+
+		match player.into() {
+			PlayerIdentifier::SteamID(steam_id) => params.steam_id = Some(steam_id),
+			PlayerIdentifier::Name(name) => params.player_name = Some(name),
+		};
+
+		match map.into() {
+			MapIdentifier::ID(map_id) => params.map_id = Some(map_id),
+			MapIdentifier::Name(map_name) => params.map_name = Some(map_name),
+		};
+
+		Ok(self.get_records_with(&params).await?.remove(0))
+	}
+
+	/// `POST /records` route
+	///
+	/// Submits a new record and returns its id and the id of its replay. This requires an API
+	/// key.
+	///
+	/// If the record is rejected by [`NewRecord::validate`], no request is sent.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn submit_record(&self, record: &NewRecord) -> Result<RecordSubmission> {
+		record.validate()?;
+
+		http::request! {
+			method = POST;
+			url = format!("{}/records", self.base_url());
+			body = json(record);
+			deserialize = RecordSubmission;
+			client = self.client();
+			rate_limiter = self.rate_limiter();
+		}
+	}
+
+	/// `POST /records/:record_id/replay` route
+	///
+	/// Uploads the replay file for a previously submitted record. This requires an API key.
+	#[tracing::instrument(level = "TRACE", skip(self, replay))]
+	pub async fn upload_replay(&self, record_id: u32, replay: Vec<u8>) -> Result<()> {
+		let replay = Part::bytes(replay).file_name(format!("{record_id}.replay"));
+		let form = Form::new().part("replay", replay);
+
+		http::request! {
+			method = POST;
+			url = format!("{}/records/{record_id}/replay", self.base_url());
+			body = multipart(form);
+			client = self.client();
+			rate_limiter = self.rate_limiter();
+		}?;
+
+		Ok(())
+	}
+
+	/// `/records/replay/:replay_id` route
+	///
+	/// Downloads the `.replay` file with the given id. See [`Record::replay_download`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_replay_file(&self, replay_id: u32) -> Result<Vec<u8>> {
+		let url = format!("{}/records/replay/{replay_id}", self.base_url());
+		let response = http::coalesce::send(
+			self.client().get(url),
+			self.retry_policy(),
+			self.rate_limiter(),
+			None,
+			self.in_flight(),
+		)
+		.await?;
+
+		Ok(response.bytes().await?.to_vec())
+	}
+
+	/// `/records/replay/:replay_id` route
+	///
+	/// Downloads and parses the replay with the given id.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_replay(&self, replay_id: u32) -> Result<Replay> {
+		let replay = self.get_replay_file(replay_id).await?;
+
+		Replay::parse(&replay)
+	}
 }
 
 /// `/records/world_records` endpoint
 pub mod world_records {
-	use {super::*, crate::http::append_pairs, reqwest::Url, std::ops::RangeInclusive};
+	use {super::*, crate::http::{self, append_pairs}, reqwest::Url, std::ops::RangeInclusive};
 
 	#[allow(missing_docs)]
 	#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -309,6 +528,46 @@ pub mod world_records {
 		pub limit: Option<u32>,
 	}
 
+	impl Params {
+		/// The URL for `/records/top/world_records`, including the list parameters which are
+		/// skipped when serializing the rest of the query string.
+		pub(crate) fn url(&self, base_url: &str) -> Url {
+			let mut url = Url::parse(&format!("{base_url}/records/top/world_records"))
+				.expect("This is a valid URL.");
+
+			append_pairs!(&mut url, &self.ids, "ids");
+			append_pairs!(
+				&mut url,
+				self.modes
+					.as_ref()
+					.map(|modes| modes.iter().map(|&mode| mode as u8)),
+				"mode_ids"
+			);
+			append_pairs!(&mut url, &self.map_ids, "map_ids");
+			append_pairs!(&mut url, &self.stages, "stages");
+			append_pairs!(&mut url, &self.tickrates, "tickrates");
+
+			url
+		}
+
+		/// The parameters used by [`GlobalApi::get_wr_leaderboard`].
+		pub(crate) fn leaderboard(
+			stages: RangeInclusive<u8>,
+			mode: Mode,
+			runtype: Runtype,
+			n: usize,
+		) -> Self {
+			Self {
+				modes: Some(vec![mode]),
+				stages: Some(stages.collect()),
+				tickrates: Some(vec![128]),
+				runtype: Some(runtype),
+				limit: Some(n as u32),
+				..Default::default()
+			}
+		}
+	}
+
 	#[allow(missing_docs)]
 	#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 	pub struct RecordHolder {
@@ -317,41 +576,34 @@ pub mod world_records {
 		pub player_name: String,
 	}
 
-	/// `/records/top/world_records` route
-	///
-	/// Fetches a leaderboard of `n` world record holders.
-	#[tracing::instrument(level = "TRACE", skip(client))]
-	pub async fn get_wr_leaderboard(
-		stages: impl Into<RangeInclusive<u8>> + std::fmt::Debug,
-		mode: impl Into<Mode> + std::fmt::Debug,
-		runtype: impl Into<Runtype> + std::fmt::Debug,
-		n: usize,
-		client: &crate::http::Client,
-	) -> Result<Vec<RecordHolder>> {
-		let params = Params {
-			tickrates: Some(vec![128]),
-			runtype: Some(runtype.into()),
-			limit: Some(n as u32),
-			..Default::default()
-		};
-
-		let mut url = Url::parse(&format!("{API_URL}/records/top/world_records"))
-			.expect("This is a valid URL.");
-
-		append_pairs!(&mut url, Some(stages.into().collect::<Vec<_>>()), "stages");
-		append_pairs!(&mut url, Some(vec![mode.into() as u8]), "mode_ids");
-
-		let leaderboard = http::get! {
-			url = url;
-			params = &params;
-			deserialize = Vec<RecordHolder>;
-			client = client;
-		}?;
-
-		if leaderboard.is_empty() {
-			yeet!(EmptyResponse);
+	impl GlobalApi {
+		/// `/records/top/world_records` route
+		///
+		/// Fetches a leaderboard of `n` world record holders.
+		#[tracing::instrument(level = "TRACE", skip(self))]
+		pub async fn get_wr_leaderboard(
+			&self,
+			stages: impl Into<RangeInclusive<u8>> + std::fmt::Debug,
+			mode: impl Into<Mode> + std::fmt::Debug,
+			runtype: impl Into<Runtype> + std::fmt::Debug,
+			n: usize,
+		) -> Result<Vec<RecordHolder>> {
+			let params = Params::leaderboard(stages.into(), mode.into(), runtype.into(), n);
+			let leaderboard = http::get! {
+				url = params.url(self.base_url());
+				params = &params;
+				deserialize = Vec<RecordHolder>;
+				client = self.client();
+				retry = self.retry_policy();
+				rate_limiter = self.rate_limiter();
+				in_flight = self.in_flight();
+			}?;
+
+			if leaderboard.is_empty() {
+				yeet!(EmptyResponse);
+			}
+
+			Ok(leaderboard)
 		}
-
-		Ok(leaderboard)
 	}
 }
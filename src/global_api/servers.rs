@@ -7,8 +7,12 @@
 //! NOTE: `/servers/:server_id` seems to be broken.
 
 use {
-	super::API_URL,
-	crate::{http, http::append_pairs, yeet, Result, SteamID},
+	super::GlobalApi,
+	crate::{
+		http::{self, append_pairs, Pagination},
+		yeet, Result, SteamID,
+	},
+	futures::Stream,
 	reqwest::Url,
 	serde::{Deserialize, Serialize},
 	std::net::{IpAddr, SocketAddr},
@@ -53,58 +57,92 @@ pub struct Params {
 	pub limit: Option<u32>,
 }
 
-/// `/servers` route
-///
-/// Fetches servers with the given `params`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_servers_with(params: &Params, client: &http::Client) -> Result<Vec<Server>> {
-	let mut url = Url::parse(&format!("{API_URL}/servers")).expect("This is a valid URL.");
-
-	append_pairs!(&mut url, &params.ids, "id");
-
-	let servers = http::get! {
-		url = url;
-		params = params;
-		deserialize = Vec<Server>;
-		client = client;
-	}?;
-
-	if servers.is_empty() {
-		yeet!(EmptyResponse);
-	}
+impl Params {
+	/// The URL for `/servers`, including the list parameters which are skipped when serializing
+	/// the rest of the query string.
+	pub(crate) fn url(&self, base_url: &str) -> Url {
+		let mut url = Url::parse(&format!("{base_url}/servers")).expect("This is a valid URL.");
 
-	Ok(servers)
-}
+		append_pairs!(&mut url, &self.ids, "id");
 
-/// `/servers` route
-///
-/// Fetches servers owned by the given `server_owner`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_servers_owned_by(
-	server_owner: impl Into<SteamID> + std::fmt::Debug,
-	client: &http::Client,
-) -> Result<Vec<Server>> {
-	let params = Params { owned_by: Some(server_owner.into()), ..Default::default() };
-
-	get_servers_with(&params, client).await
+		url
+	}
 }
 
-/// `/servers/name/:server_name` route
-///
-/// Fetches a single server with the given `name`.
-///
-/// If the API response is empty, this function will return an [`Error`](crate::Error).
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_server(name: &str, client: &http::Client) -> Result<Server> {
-	let server = http::get! {
-		url = format!("{API_URL}/servers/{name}");
-		deserialize = Server;
-		client = client;
-	}?;
-
-	Ok(server)
+impl GlobalApi {
+	/// `/servers` route
+	///
+	/// Fetches servers with the given `params`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_servers_with(&self, params: &Params) -> Result<Vec<Server>> {
+		let servers = http::get! {
+			url = params.url(self.base_url());
+			params = params;
+			deserialize = Vec<Server>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		if servers.is_empty() {
+			yeet!(EmptyResponse);
+		}
+
+		Ok(servers)
+	}
+
+	/// `/servers` route
+	///
+	/// Same as [`Self::get_servers_with`] but returns a [`Stream`] which fetches pages of
+	/// [`Pagination::page_size`] servers, starting at `params.offset`, until there are no more
+	/// servers left. `params.limit` is ignored.
+	pub fn stream_servers_with<'a>(
+		&'a self,
+		params: &Params,
+		pagination: Pagination,
+	) -> impl Stream<Item = Result<Server>> + 'a {
+		let params = params.clone();
+
+		http::pagination::paginate(params.offset.unwrap_or(0), pagination, move |offset, limit| {
+			let params = Params { offset: Some(offset), limit: Some(limit), ..params.clone() };
+			async move { self.get_servers_with(&params).await }
+		})
+	}
+
+	/// `/servers` route
+	///
+	/// Fetches servers owned by the given `server_owner`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_servers_owned_by(
+		&self,
+		server_owner: impl Into<SteamID> + std::fmt::Debug,
+	) -> Result<Vec<Server>> {
+		let params = Params { owned_by: Some(server_owner.into()), ..Default::default() };
+
+		self.get_servers_with(&params).await
+	}
+
+	/// `/servers/name/:server_name` route
+	///
+	/// Fetches a single server with the given `name`.
+	///
+	/// If the API response is empty, this function will return an [`Error`](crate::Error).
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_server(&self, name: &str) -> Result<Server> {
+		let server = http::get! {
+			url = format!("{}/servers/{name}", self.base_url());
+			deserialize = Server;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}?;
+
+		Ok(server)
+	}
 }
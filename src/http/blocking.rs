@@ -0,0 +1,147 @@
+//! Synchronous counterparts of [`retry::send`](super::retry), [`json`](super::json) and
+//! [`paginate`](super::pagination) used by the [blocking](crate::blocking) clients.
+//!
+//! Requests are sent with [`reqwest::blocking`], which cannot be used while a Tokio runtime is
+//! entered. Instead of panicking, [`send`] returns [`Error::BlockingInAsyncContext`] in that case.
+
+use {
+	super::{retry::retry_after, RateLimiter, RetryPolicy},
+	crate::{yeet, Error, Result},
+	::serde::de::DeserializeOwned,
+	reqwest::{
+		blocking::{Client, Request, Response},
+		StatusCode,
+	},
+};
+
+/// Sends the given `request`, retrying it according to `policy`.
+///
+/// If a `rate_limiter` is given, every attempt waits on it before being sent.
+pub(crate) fn send(
+	client: &Client,
+	request: Request,
+	policy: &RetryPolicy,
+	rate_limiter: Option<&RateLimiter>,
+) -> Result<Response> {
+	if tokio::runtime::Handle::try_current().is_ok() {
+		yeet!(BlockingInAsyncContext);
+	}
+
+	let max_attempts = policy.max_attempts.max(1);
+	let host = request
+		.url()
+		.host_str()
+		.unwrap_or_default()
+		.to_owned();
+
+	let mut retry_after = None;
+	let mut attempt = 1;
+
+	loop {
+		if attempt > 1 {
+			let delay = policy.delay(attempt, retry_after.take());
+			tracing::debug!(?delay, attempt, "retrying request");
+			std::thread::sleep(delay);
+		}
+
+		if let Some(rate_limiter) = rate_limiter {
+			rate_limiter.wait(&host);
+		}
+
+		let _span =
+			tracing::trace_span!("attempt", attempt, status = tracing::field::Empty).entered();
+
+		// Requests with a streaming body cannot be cloned and therefore not be retried.
+		let Some(current) = request.try_clone() else {
+			return send_once(client, request);
+		};
+
+		match send_once(client, current) {
+			Err(error) if error.is_retryable() && attempt < max_attempts => {
+				tracing::debug!(%error, attempt, "request failed");
+				retry_after = error.retry_after();
+			}
+			result => return result,
+		}
+
+		attempt += 1;
+	}
+}
+
+fn send_once(client: &Client, request: Request) -> Result<Response> {
+	let response = client.execute(request)?;
+	let status = response.status();
+	tracing::Span::current().record("status", status.as_u16());
+
+	if status == StatusCode::NO_CONTENT {
+		return Err(Error::EmptyResponse);
+	}
+
+	if let Err(err) = response.error_for_status_ref() {
+		return Err(match Error::from(err) {
+			Error::RateLimited { url, .. } => {
+				Error::RateLimited { url, retry_after: retry_after(response.headers()) }
+			}
+			error => error,
+		});
+	}
+
+	Ok(response)
+}
+
+/// Deserializes the body of the given `response` as JSON. See [`json`](super::json).
+pub(crate) fn json<T: DeserializeOwned>(response: Response) -> Result<T> {
+	let url = response.url().to_string();
+	let body = response.text()?;
+
+	super::decode(url, body)
+}
+
+/// Turns a function fetching a single page into an [`Iterator`] over all items.
+///
+/// This behaves exactly like [`paginate`](super::pagination), but fetches every page on the
+/// current thread. The iterator stops after yielding the first error.
+#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+pub(crate) fn paginate<'a, T, F>(
+	offset: u32,
+	pagination: super::Pagination,
+	mut fetch_page: F,
+) -> impl Iterator<Item = Result<T>> + 'a
+where
+	T: 'a,
+	F: FnMut(u32, u32) -> Result<Vec<T>> + 'a, {
+	let page_size = pagination.page_size.max(1);
+	let mut offset = offset;
+	let mut remaining = pagination.max_items;
+	let mut done = false;
+	let mut page = Vec::new().into_iter();
+
+	std::iter::from_fn(move || loop {
+		if let Some(item) = page.next() {
+			return Some(Ok(item));
+		}
+
+		if done || remaining == Some(0) {
+			return None;
+		}
+
+		let limit =
+			remaining.map_or(page_size, |remaining| remaining.min(page_size as usize) as u32);
+
+		let mut items = match fetch_page(offset, limit) {
+			Ok(items) => items,
+			Err(Error::EmptyResponse) => Vec::new(),
+			Err(err) => {
+				done = true;
+				return Some(Err(err));
+			}
+		};
+
+		let fetched = items.len().min(limit as usize);
+		offset = offset.saturating_add(fetched as u32);
+		remaining = remaining.map(|remaining| remaining.saturating_sub(fetched));
+		done = fetched < limit as usize;
+		items.truncate(fetched);
+		page = items.into_iter();
+	})
+}
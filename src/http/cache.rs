@@ -0,0 +1,291 @@
+//! Caching API responses.
+//!
+//! Endpoints whose data rarely changes (e.g. map lists or record filters) can be cached by
+//! configuring a [`ResponseCache`] on the API client. Responses are keyed by their full URL,
+//! including the query string, and stored for a per-endpoint TTL. Once an entry has expired, it
+//! is revalidated using `ETag` / `Last-Modified` if the server sent them.
+//!
+//! [`MemoryCache`] is an in-memory LRU cache; other backends (e.g. on disk or Redis) can be used
+//! by implementing [`Cache`].
+
+use {
+	super::{retry, RateLimiter, RetryPolicy},
+	crate::Result,
+	futures::future::{self, BoxFuture, FutureExt},
+	reqwest::{
+		header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+		RequestBuilder, Response, ResponseBuilderExt, StatusCode, Url,
+	},
+	serde::{Deserialize, Serialize},
+	std::{
+		collections::{BTreeMap, HashMap},
+		fmt::Debug,
+		hash::{Hash, Hasher},
+		sync::{Arc, Mutex},
+		time::{Duration, SystemTime},
+	},
+};
+
+/// A cached response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheEntry {
+	/// The response body.
+	pub body: Vec<u8>,
+
+	/// The `ETag` header of the response, if any.
+	pub etag: Option<String>,
+
+	/// The `Last-Modified` header of the response, if any.
+	pub last_modified: Option<String>,
+
+	/// The point in time after which this entry has to be revalidated.
+	pub expires_at: SystemTime,
+}
+
+impl CacheEntry {
+	/// Whether this entry can still be used without revalidating it.
+	pub fn is_fresh(&self) -> bool {
+		self.expires_at > SystemTime::now()
+	}
+}
+
+/// A storage backend for cached responses.
+pub trait Cache: Debug + Send + Sync {
+	/// Fetches the entry for the given `key`, if there is one.
+	fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<CacheEntry>>;
+
+	/// Inserts or replaces the entry for the given `key`.
+	fn insert(&self, key: String, entry: CacheEntry) -> BoxFuture<'_, ()>;
+}
+
+/// An in-memory [`Cache`] which evicts the least recently used entry once it is full.
+#[derive(Debug)]
+pub struct MemoryCache {
+	capacity: usize,
+	entries: Mutex<MemoryEntries>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryEntries {
+	/// Increases with every access; used to find the least recently used entry.
+	clock: u64,
+	entries: HashMap<String, (CacheEntry, u64)>,
+}
+
+impl MemoryCache {
+	/// Constructs a new [`MemoryCache`] which holds up to `capacity` entries.
+	pub fn new(capacity: usize) -> Self {
+		Self { capacity: capacity.max(1), entries: Mutex::default() }
+	}
+
+	fn entries(&self) -> std::sync::MutexGuard<'_, MemoryEntries> {
+		self.entries
+			.lock()
+			.expect("The cache lock is never held across a panic.")
+	}
+}
+
+impl Cache for MemoryCache {
+	fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<CacheEntry>> {
+		let mut entries = self.entries();
+		entries.clock += 1;
+
+		let clock = entries.clock;
+		let entry = entries.entries.get_mut(key).map(|(entry, last_used)| {
+			*last_used = clock;
+			entry.clone()
+		});
+
+		future::ready(entry).boxed()
+	}
+
+	fn insert(&self, key: String, entry: CacheEntry) -> BoxFuture<'_, ()> {
+		let mut entries = self.entries();
+		entries.clock += 1;
+
+		if entries.entries.len() >= self.capacity && !entries.entries.contains_key(&key) {
+			let least_recently_used = entries
+				.entries
+				.iter()
+				.min_by_key(|(_, (_, last_used))| *last_used)
+				.map(|(key, _)| key.clone());
+
+			if let Some(key) = least_recently_used {
+				entries.entries.remove(&key);
+			}
+		}
+
+		let clock = entries.clock;
+		entries.entries.insert(key, (entry, clock));
+
+		future::ready(()).boxed()
+	}
+}
+
+/// A [`Cache`] backend together with the TTLs for each endpoint.
+///
+/// Two [`ResponseCache`]s are equal if they share the same backend and TTLs.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+	backend: Arc<dyn Cache>,
+	ttls: BTreeMap<String, Duration>,
+}
+
+impl ResponseCache {
+	/// Constructs a new [`ResponseCache`] using the default TTL of each endpoint.
+	pub fn new(backend: impl Cache + 'static) -> Self {
+		Self { backend: Arc::new(backend), ttls: BTreeMap::new() }
+	}
+
+	/// Constructs a new [`ResponseCache`] with an in-memory LRU cache holding up to `capacity`
+	/// entries.
+	pub fn in_memory(capacity: usize) -> Self {
+		Self::new(MemoryCache::new(capacity))
+	}
+
+	/// Overrides the TTL for the given `route` (e.g. `"/maps"`).
+	pub fn ttl(mut self, route: impl Into<String>, ttl: Duration) -> Self {
+		self.ttls.insert(route.into(), ttl);
+		self
+	}
+
+	/// The underlying [`Cache`] backend.
+	pub fn backend(&self) -> &dyn Cache {
+		&*self.backend
+	}
+
+	/// The TTL for the given `route`, falling back to `default`.
+	pub fn ttl_for(&self, route: &str, default: Duration) -> Duration {
+		self.ttls.get(route).copied().unwrap_or(default)
+	}
+}
+
+impl PartialEq for ResponseCache {
+	fn eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.backend, &other.backend) && self.ttls == other.ttls
+	}
+}
+
+impl Eq for ResponseCache {}
+
+impl Hash for ResponseCache {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		Arc::as_ptr(&self.backend)
+			.cast::<()>()
+			.hash(state);
+		self.ttls.hash(state);
+	}
+}
+
+fn header(response: &Response, name: reqwest::header::HeaderName) -> Option<String> {
+	response
+		.headers()
+		.get(name)?
+		.to_str()
+		.ok()
+		.map(ToOwned::to_owned)
+}
+
+fn cached_response(url: &Url, entry: CacheEntry) -> Response {
+	let response = ::http::Response::builder()
+		.url(url.clone())
+		.body(entry.body)
+		.expect("A response with only a body is always valid.");
+
+	Response::from(response)
+}
+
+/// Sends the given `request`, serving it from `cache` if possible.
+///
+/// `cache` consists of the client's [`ResponseCache`], the name of the route and its default TTL.
+/// Without a cache, this is the same as [`retry::send`].
+pub(crate) async fn send(
+	request: RequestBuilder,
+	policy: &RetryPolicy,
+	rate_limiter: Option<&RateLimiter>,
+	cache: Option<(Option<&ResponseCache>, &str, Duration)>,
+) -> Result<Response> {
+	let Some((Some(cache), route, default_ttl)) = cache else {
+		return retry::send(request, policy, rate_limiter).await;
+	};
+
+	let Some(url) = request
+		.try_clone()
+		.and_then(|request| request.build().ok())
+		.map(|request| request.url().clone())
+	else {
+		return retry::send(request, policy, rate_limiter).await;
+	};
+
+	let key = url.to_string();
+
+	let ttl = cache.ttl_for(route, default_ttl);
+	let entry = cache.backend().get(&key).await;
+	let mut request = request;
+
+	if let Some(entry) = entry.as_ref() {
+		if entry.is_fresh() {
+			tracing::trace!(key, "cache hit");
+			return Ok(cached_response(&url, entry.clone()));
+		}
+
+		if let Some(etag) = &entry.etag {
+			request = request.header(IF_NONE_MATCH, etag);
+		}
+
+		if let Some(last_modified) = &entry.last_modified {
+			request = request.header(IF_MODIFIED_SINCE, last_modified);
+		}
+	}
+
+	let response = retry::send(request, policy, rate_limiter).await?;
+	let expires_at = SystemTime::now() + ttl;
+
+	if let (StatusCode::NOT_MODIFIED, Some(entry)) = (response.status(), entry) {
+		tracing::trace!(key, "cache entry revalidated");
+		let entry = CacheEntry { expires_at, ..entry };
+		cache.backend().insert(key, entry.clone()).await;
+		return Ok(cached_response(&url, entry));
+	}
+
+	if response.status() != StatusCode::OK {
+		return Ok(response);
+	}
+
+	let etag = header(&response, ETAG);
+	let last_modified = header(&response, LAST_MODIFIED);
+	let body = response.bytes().await?.to_vec();
+
+	let entry = CacheEntry { body, etag, last_modified, expires_at };
+	cache.backend().insert(key, entry.clone()).await;
+
+	Ok(cached_response(&url, entry))
+}
+
+#[cfg(test)]
+mod tests {
+	use {super::*, pretty_assertions::assert_eq};
+
+	fn entry(body: &str) -> CacheEntry {
+		CacheEntry {
+			body: body.as_bytes().to_vec(),
+			etag: None,
+			last_modified: None,
+			expires_at: SystemTime::now() + Duration::from_secs(60),
+		}
+	}
+
+	#[tokio::test]
+	async fn memory_cache_evicts_least_recently_used() {
+		let cache = MemoryCache::new(2);
+
+		cache.insert(String::from("a"), entry("a")).await;
+		cache.insert(String::from("b"), entry("b")).await;
+		assert!(cache.get("a").await.is_some());
+
+		cache.insert(String::from("c"), entry("c")).await;
+		assert_eq!(cache.get("a").await.map(|entry| entry.body), Some(b"a".to_vec()));
+		assert!(cache.get("b").await.is_none());
+		assert!(cache.get("c").await.is_some());
+	}
+}
@@ -0,0 +1,349 @@
+//! Configurable API clients.
+//!
+//! Every API wrapper in this crate has its own client type (e.g.
+//! `GlobalApi`) but they all share the same configuration options, represented by [`Config`].
+
+use {
+	super::{RateLimit, ResponseCache, RetryPolicy},
+	crate::{Error, Result},
+	reqwest::{
+		header::{HeaderMap, HeaderValue},
+		Client, Url,
+	},
+	std::time::Duration,
+};
+
+/// The name of the header used to authenticate with an API key.
+pub const API_KEY_HEADER: &str = "X-ApiKey";
+
+/// Options shared by all API client builders.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Config {
+	/// The base URL every request path is appended to.
+	pub base_url: String,
+
+	/// The URL used for health checks, if the API has one.
+	pub health_url: Option<String>,
+
+	/// An API key which will be sent with every request in the [`API_KEY_HEADER`] header.
+	pub api_key: Option<String>,
+
+	/// The timeout for each request.
+	pub timeout: Option<Duration>,
+
+	/// The `User-Agent` header sent with every request.
+	pub user_agent: Option<String>,
+
+	/// How failed requests are retried.
+	pub retry: RetryPolicy,
+
+	/// How many requests may be sent to a single host.
+	pub rate_limit: Option<RateLimit>,
+
+	/// The cache used for endpoints whose data rarely changes.
+	pub cache: Option<ResponseCache>,
+
+	/// Whether identical concurrent requests should share a single response.
+	pub coalesce_requests: bool,
+}
+
+impl Config {
+	/// Creates a new [`Config`] with the given `base_url` and all other options unset.
+	pub fn new(base_url: impl Into<String>) -> Self {
+		Self {
+			base_url: base_url.into(),
+			health_url: None,
+			api_key: None,
+			timeout: None,
+			user_agent: None,
+			retry: RetryPolicy::new(),
+			rate_limit: None,
+			cache: None,
+			coalesce_requests: true,
+		}
+	}
+
+	/// Validates the configured base URL and strips any trailing slashes from it.
+	pub fn base_url(&self) -> Result<String> {
+		let base_url = self.base_url.trim_end_matches('/');
+
+		if Url::parse(base_url).is_err() {
+			return Err(Error::InvalidUrl(base_url.to_owned()));
+		}
+
+		Ok(base_url.to_owned())
+	}
+
+	/// Validates the configured health URL.
+	pub fn health_url(&self) -> Result<Option<String>> {
+		let Some(health_url) = &self.health_url else {
+			return Ok(None);
+		};
+
+		if Url::parse(health_url).is_err() {
+			return Err(Error::InvalidUrl(health_url.to_owned()));
+		}
+
+		Ok(Some(health_url.to_owned()))
+	}
+
+	/// Builds a [`Client`] with all the configured options.
+	pub fn client(&self) -> Result<Client> {
+		let mut client = Client::builder().default_headers(self.default_headers()?);
+
+		if let Some(timeout) = self.timeout {
+			client = client.timeout(timeout);
+		}
+
+		if let Some(user_agent) = &self.user_agent {
+			client = client.user_agent(user_agent);
+		}
+
+		client
+			.build()
+			.map_err(Error::from)
+	}
+
+	/// Builds a [`reqwest::blocking::Client`] with all the configured options.
+	///
+	/// This must not be called while a Tokio runtime is entered.
+	#[cfg(feature = "blocking")]
+	pub fn blocking_client(&self) -> Result<reqwest::blocking::Client> {
+		// Unlike the async client, the blocking client defaults to a 30s timeout instead of none.
+		let mut client = reqwest::blocking::Client::builder()
+			.default_headers(self.default_headers()?)
+			.timeout(self.timeout);
+
+		if let Some(user_agent) = &self.user_agent {
+			client = client.user_agent(user_agent);
+		}
+
+		client
+			.build()
+			.map_err(Error::from)
+	}
+
+	/// The headers sent with every request.
+	fn default_headers(&self) -> Result<HeaderMap> {
+		let mut headers = HeaderMap::new();
+
+		if let Some(api_key) = &self.api_key {
+			let mut api_key = HeaderValue::from_str(api_key)
+				.map_err(|_| Error::Custom(String::from("API key is not a valid header value.")))?;
+
+			api_key.set_sensitive(true);
+			headers.insert(API_KEY_HEADER, api_key);
+		}
+
+		Ok(headers)
+	}
+}
+
+/// Generates an API client type and its builder.
+///
+/// The generated client holds a [`Client`] and a base URL, which every endpoint method uses to
+/// construct its request URL. APIs with a health endpoint outside of the base URL can specify it
+/// as an optional fourth argument.
+#[cfg(any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api"))]
+macro_rules! api_client {
+	(@health_url) => {
+		None
+	};
+
+	(@health_url $health_url:expr) => {
+		Some(String::from($health_url))
+	};
+
+	(
+		$(#[$meta:meta])*
+		$name:ident, $builder:ident, $default_url:expr $(, $default_health_url:expr)?
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone)]
+		pub struct $name {
+			client: $crate::http::Client,
+			base_url: String,
+			health_url: Option<String>,
+			retry: $crate::http::RetryPolicy,
+			rate_limiter: Option<$crate::http::RateLimiter>,
+			cache: Option<$crate::http::ResponseCache>,
+			in_flight: Option<$crate::http::InFlightRequests>,
+		}
+
+		impl $name {
+			/// Constructs a new client with the default configuration.
+			pub fn new() -> Self {
+				Self::with_client($crate::http::Client::new())
+			}
+
+			/// Constructs a new client from an existing [`Client`](crate::http::Client) using the
+			/// default base URL.
+			pub fn with_client(client: $crate::http::Client) -> Self {
+				let health_url = $crate::http::api_client!(@health_url $($default_health_url)?);
+
+				Self {
+					client,
+					base_url: String::from($default_url),
+					health_url,
+					retry: $crate::http::RetryPolicy::new(),
+					rate_limiter: None,
+					cache: None,
+					in_flight: Some($crate::http::InFlightRequests::new()),
+				}
+			}
+
+			/// Returns a builder to configure a new client.
+			pub fn builder() -> $builder {
+				$builder::default()
+			}
+
+			/// The base URL every request path is appended to.
+			pub fn base_url(&self) -> &str {
+				&self.base_url
+			}
+
+			/// The URL used for health checks, if the API has one.
+			pub fn health_url(&self) -> Option<&str> {
+				self.health_url.as_deref()
+			}
+
+			/// The underlying [`Client`](crate::http::Client) used to make requests.
+			pub fn client(&self) -> &$crate::http::Client {
+				&self.client
+			}
+
+			/// The [`RetryPolicy`](crate::http::RetryPolicy) applied to every request.
+			pub fn retry_policy(&self) -> &$crate::http::RetryPolicy {
+				&self.retry
+			}
+
+			/// The [`RateLimiter`](crate::http::RateLimiter) every request waits on, if any.
+			///
+			/// All clones of this client share the same rate limiter.
+			pub fn rate_limiter(&self) -> Option<&$crate::http::RateLimiter> {
+				self.rate_limiter.as_ref()
+			}
+
+			/// The [`ResponseCache`](crate::http::ResponseCache) used for endpoints whose data
+			/// rarely changes, if any.
+			pub fn cache(&self) -> Option<&$crate::http::ResponseCache> {
+				self.cache.as_ref()
+			}
+
+			/// The requests this client is currently waiting on, unless request coalescing
+			/// has been disabled.
+			///
+			/// All clones of this client share the same in-flight requests.
+			pub fn in_flight(&self) -> Option<&$crate::http::InFlightRequests> {
+				self.in_flight.as_ref()
+			}
+		}
+
+		impl Default for $name {
+			fn default() -> Self {
+				Self::new()
+			}
+		}
+
+		impl From<$crate::http::Client> for $name {
+			fn from(client: $crate::http::Client) -> Self {
+				Self::with_client(client)
+			}
+		}
+
+		#[doc = concat!("Builder for [`", stringify!($name), "`].")]
+		#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+		pub struct $builder {
+			config: $crate::http::Config,
+		}
+
+		impl Default for $builder {
+			fn default() -> Self {
+				let mut config = $crate::http::Config::new($default_url);
+				config.health_url = $crate::http::api_client!(@health_url $($default_health_url)?);
+
+				Self { config }
+			}
+		}
+
+		impl $builder {
+			/// Sets the base URL every request path is appended to.
+			pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+				self.config.base_url = base_url.into();
+				self
+			}
+
+			/// Sets the URL used for health checks.
+			pub fn health_url(mut self, health_url: impl Into<String>) -> Self {
+				self.config.health_url = Some(health_url.into());
+				self
+			}
+
+			/// Sets an API key which will be sent with every request.
+			pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+				self.config.api_key = Some(api_key.into());
+				self
+			}
+
+			/// Sets the timeout for each request.
+			pub fn timeout(mut self, timeout: ::std::time::Duration) -> Self {
+				self.config.timeout = Some(timeout);
+				self
+			}
+
+			/// Sets the `User-Agent` header sent with every request.
+			pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+				self.config.user_agent = Some(user_agent.into());
+				self
+			}
+
+			/// Sets how failed requests are retried.
+			pub fn retry(mut self, retry: $crate::http::RetryPolicy) -> Self {
+				self.config.retry = retry;
+				self
+			}
+
+			/// Limits how many requests may be sent to a single host.
+			pub fn rate_limit(mut self, rate_limit: $crate::http::RateLimit) -> Self {
+				self.config.rate_limit = Some(rate_limit);
+				self
+			}
+
+			/// Caches responses of endpoints whose data rarely changes (e.g. map lists).
+			pub fn cache(mut self, cache: $crate::http::ResponseCache) -> Self {
+				self.config.cache = Some(cache);
+				self
+			}
+
+			/// Sets whether identical concurrent requests should share a single response.
+			///
+			/// This is enabled by default.
+			pub fn coalesce_requests(mut self, coalesce_requests: bool) -> Self {
+				self.config.coalesce_requests = coalesce_requests;
+				self
+			}
+
+			/// Builds the client.
+			///
+			/// This will fail if the base URL is invalid or the underlying
+			/// [`Client`](crate::http::Client) cannot be constructed.
+			pub fn build(self) -> $crate::Result<$name> {
+				Ok($name {
+					client: self.config.client()?,
+					base_url: self.config.base_url()?,
+					health_url: self.config.health_url()?,
+					retry: self.config.retry,
+					rate_limiter: self.config.rate_limit.map($crate::http::RateLimiter::new),
+					cache: self.config.cache,
+					in_flight: self
+						.config
+						.coalesce_requests
+						.then($crate::http::InFlightRequests::new),
+				})
+			}
+		}
+	};
+}
+
+#[cfg(any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api"))]
+pub(crate) use api_client;
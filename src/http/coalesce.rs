@@ -0,0 +1,196 @@
+//! Coalescing identical concurrent requests.
+//!
+//! If multiple tasks send the same request (same URL and query string) at the same time, only
+//! the first one actually hits the network. Every other task waits for that request to finish
+//! and receives a copy of its response (or error).
+
+use {
+	super::{RateLimiter, ResponseCache, RetryPolicy, cache},
+	crate::Result,
+	futures::future::{BoxFuture, FutureExt, Shared},
+	reqwest::{RequestBuilder, Response, ResponseBuilderExt, StatusCode, Url},
+	std::{
+		collections::HashMap,
+		fmt,
+		sync::{Arc, Mutex},
+		time::Duration,
+	},
+};
+
+type InFlightRequest = Shared<BoxFuture<'static, Result<Arc<Body>>>>;
+
+/// The requests an API client is currently waiting on.
+///
+/// Cloning [`InFlightRequests`] is cheap and all clones share the same requests.
+#[derive(Clone, Default)]
+pub struct InFlightRequests {
+	requests: Arc<Mutex<HashMap<String, InFlightRequest>>>,
+}
+
+impl fmt::Debug for InFlightRequests {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("InFlightRequests")
+			.field("len", &self.len())
+			.finish()
+	}
+}
+
+impl InFlightRequests {
+	/// Constructs a new, empty [`InFlightRequests`].
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The amount of requests currently in flight.
+	pub fn len(&self) -> usize {
+		self.requests().len()
+	}
+
+	/// Whether there are no requests in flight.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn requests(&self) -> std::sync::MutexGuard<'_, HashMap<String, InFlightRequest>> {
+		self.requests
+			.lock()
+			.expect("The in-flight lock is never held across a panic.")
+	}
+}
+
+/// A response that has been read to completion and can be shared between tasks.
+#[derive(Debug)]
+struct Body {
+	url: Url,
+	status: StatusCode,
+	bytes: Vec<u8>,
+}
+
+impl Body {
+	fn to_response(&self) -> Response {
+		let response = ::http::Response::builder()
+			.url(self.url.clone())
+			.status(self.status)
+			.body(self.bytes.clone())
+			.expect("A response with only a status and body is always valid.");
+
+		Response::from(response)
+	}
+}
+
+/// Sends the given `request`, sharing the response with identical requests which are already in
+/// flight.
+///
+/// Without `in_flight`, this is the same as [`cache::send`].
+pub(crate) async fn send(
+	request: RequestBuilder,
+	policy: &RetryPolicy,
+	rate_limiter: Option<&RateLimiter>,
+	cache: Option<(Option<&ResponseCache>, &str, Duration)>,
+	in_flight: Option<&InFlightRequests>,
+) -> Result<Response> {
+	let Some(in_flight) = in_flight else {
+		return cache::send(request, policy, rate_limiter, cache).await;
+	};
+
+	let Some(key) = request
+		.try_clone()
+		.and_then(|request| request.build().ok())
+		.map(|request| request.url().to_string())
+	else {
+		return cache::send(request, policy, rate_limiter, cache).await;
+	};
+
+	let in_flight_request = in_flight
+		.requests()
+		.entry(key.clone())
+		.or_insert_with(|| {
+			tracing::trace!(key, "sending request");
+
+			let policy = *policy;
+			let rate_limiter = rate_limiter.cloned();
+			let cache = cache.map(|(cache, route, ttl)| (cache.cloned(), route.to_owned(), ttl));
+			let in_flight = in_flight.clone();
+
+			async move {
+				let cache = cache
+					.as_ref()
+					.map(|(cache, route, ttl)| (cache.as_ref(), route.as_str(), *ttl));
+
+				let body = async {
+					let response =
+						cache::send(request, &policy, rate_limiter.as_ref(), cache).await?;
+					let url = response.url().clone();
+					let status = response.status();
+					let bytes = response.bytes().await?.to_vec();
+
+					Ok(Arc::new(Body { url, status, bytes }))
+				}
+				.await;
+
+				in_flight.requests().remove(&key);
+				body
+			}
+			.boxed()
+			.shared()
+		})
+		.clone();
+
+	in_flight_request
+		.await
+		.map(|body| body.to_response())
+}
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		pretty_assertions::assert_eq,
+		std::sync::atomic::{AtomicUsize, Ordering},
+		tokio::{
+			io::{AsyncReadExt, AsyncWriteExt},
+			net::TcpListener,
+		},
+	};
+
+	#[tokio::test]
+	async fn identical_requests_are_coalesced() {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let url = format!("http://{}/maps", listener.local_addr().unwrap());
+		let connections = Arc::new(AtomicUsize::new(0));
+
+		tokio::spawn({
+			let connections = Arc::clone(&connections);
+			async move {
+				loop {
+					let (mut stream, _) = listener.accept().await.unwrap();
+					connections.fetch_add(1, Ordering::SeqCst);
+					tokio::spawn(async move {
+						let mut buf = [0; 1024];
+						let _ = stream.read(&mut buf).await.unwrap();
+						tokio::time::sleep(Duration::from_millis(100)).await;
+						stream
+							.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n[]")
+							.await
+							.unwrap();
+					});
+				}
+			}
+		});
+
+		let in_flight = InFlightRequests::new();
+		let client = reqwest::Client::new();
+		let request = || client.get(&url).query(&[("limit", 1)]);
+		let policy = RetryPolicy::none();
+
+		let (a, b) = tokio::join!(
+			send(request(), &policy, None, None, Some(&in_flight)),
+			send(request(), &policy, None, None, Some(&in_flight)),
+		);
+
+		assert_eq!(a.unwrap().text().await.unwrap(), "[]");
+		assert_eq!(b.unwrap().text().await.unwrap(), "[]");
+		assert_eq!(connections.load(Ordering::SeqCst), 1);
+		assert!(in_flight.is_empty());
+	}
+}
@@ -0,0 +1,76 @@
+//! The HTTP server shared by the in-process mock APIs.
+
+use {
+	crate::{Error, Result},
+	axum::Router,
+	std::{
+		net::{Ipv4Addr, SocketAddr, TcpListener},
+		thread::JoinHandle,
+	},
+	tokio::sync::oneshot,
+};
+
+/// Serves a [`Router`] on a random local port from its own thread.
+///
+/// The server shuts down when this value is dropped.
+#[derive(Debug)]
+pub(crate) struct Server {
+	addr: SocketAddr,
+	shutdown: Option<oneshot::Sender<()>>,
+	thread: Option<JoinHandle<()>>,
+}
+
+impl Server {
+	/// Starts serving `router`.
+	pub(crate) fn start(router: Router) -> Result<Self> {
+		let error =
+			|err: std::io::Error| Error::Custom(format!("Failed to start mock server: {err}"));
+
+		let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(error)?;
+		let addr = listener.local_addr().map_err(error)?;
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.enable_io()
+			.build()
+			.map_err(error)?;
+
+		let (shutdown, signal) = oneshot::channel::<()>();
+
+		let server = {
+			let _runtime = runtime.enter();
+
+			axum::Server::from_tcp(listener)
+				.map_err(|err| Error::Custom(format!("Failed to start mock server: {err}")))?
+				.serve(router.into_make_service())
+				.with_graceful_shutdown(async {
+					signal.await.ok();
+				})
+		};
+
+		let thread = std::thread::spawn(move || {
+			if let Err(err) = runtime.block_on(server) {
+				tracing::error!(%err, "mock server failed");
+			}
+		});
+
+		tracing::debug!(%addr, "started mock server");
+
+		Ok(Self { addr, shutdown: Some(shutdown), thread: Some(thread) })
+	}
+
+	/// The address the server is listening on.
+	pub(crate) const fn addr(&self) -> SocketAddr {
+		self.addr
+	}
+}
+
+impl Drop for Server {
+	fn drop(&mut self) {
+		if let Some(shutdown) = self.shutdown.take() {
+			shutdown.send(()).ok();
+		}
+
+		if let Some(thread) = self.thread.take() {
+			thread.join().ok();
+		}
+	}
+}
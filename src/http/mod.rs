@@ -1,17 +1,94 @@
 //! Module containing HTTP functionality to interact with various APIs.
 
+use {
+	crate::{Error, Result},
+	::serde::de::DeserializeOwned,
+	reqwest::Response,
+};
+
 pub mod serde;
 pub use reqwest::{self, Client, StatusCode};
 
+pub mod client;
+#[cfg(any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api"))]
+pub(crate) use client::api_client;
+pub use client::{Config, API_KEY_HEADER};
+
+pub mod pagination;
+pub use pagination::Pagination;
+
+pub mod retry;
+pub use retry::RetryPolicy;
+
+pub mod rate_limit;
+pub use rate_limit::{RateLimit, RateLimiter};
+
+pub mod cache;
+pub use cache::{Cache, MemoryCache, ResponseCache};
+
+pub mod coalesce;
+pub use coalesce::InFlightRequests;
+
+#[cfg(all(
+	feature = "blocking",
+	any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api")
+))]
+pub(crate) mod blocking;
+
+#[cfg(feature = "mock")]
+pub(crate) mod mock;
+
+/// The maximum amount of characters of a response body included in [`Error::Decode`].
+const BODY_SNIPPET_LEN: usize = 256;
+
+/// Deserializes the body of the given `response` as JSON.
+///
+/// If this fails, the returned [`Error::Decode`] contains the path to the value that failed to
+/// deserialize (e.g. `[3].difficulty`) as well as the raw response body.
+pub(crate) async fn json<T: DeserializeOwned>(response: Response) -> Result<T> {
+	let url = response.url().to_string();
+	let body = response.text().await?;
+
+	decode(url, body)
+}
+
+/// Deserializes a response `body` received from `url`. See [`json`].
+pub(crate) fn decode<T: DeserializeOwned>(url: String, body: String) -> Result<T> {
+	let deserializer = &mut serde_json::Deserializer::from_str(&body);
+
+	serde_path_to_error::deserialize(deserializer).map_err(|err| {
+		let serde_path = err.path().to_string();
+		let message = err.into_inner().to_string();
+		tracing::debug!(url, serde_path, message, body, "failed to decode response");
+
+		Error::Decode {
+			url,
+			body_snippet: body.chars().take(BODY_SNIPPET_LEN).collect(),
+			serde_path,
+			message,
+			body,
+		}
+	})
+}
+
 macro_rules! get {
 	(
 		url = $url:expr;
 		$( params = $params:expr; )?
 		$( deserialize = $response:ty; )?
 		client = $client:expr;
+		$( retry = $retry:expr; )?
+		$( rate_limiter = $rate_limiter:expr; )?
+		$( cache = $cache:expr, $route:expr, $ttl:expr; )?
+		$( in_flight = $in_flight:expr; )?
 	) => {{
 		let params = $crate::http::get!(__params $(, $params)?);
-		let request = $crate::http::get!(__req, $url, params, $client);
+		let retry = $crate::http::get!(__retry $(, $retry)?);
+		let rate_limiter = $crate::http::get!(__rate_limiter $(, $rate_limiter)?);
+		let cache = $crate::http::get!(__cache $(, $cache, $route, $ttl)?);
+		let in_flight = $crate::http::get!(__in_flight $(, $in_flight)?);
+		let request =
+			$crate::http::get!(__req, $url, params, $client, retry, rate_limiter, cache, in_flight);
 		let request = $crate::http::get!(__process, request $(, $response)?);
 		$crate::http::get!(__finish, request)
 	}};
@@ -24,64 +101,118 @@ macro_rules! get {
 		&::serde_json::json!({})
 	};
 
-	(__req, $url:expr, $params:expr, $client:expr) => {
-		$client.get($url)
-			.query($params)
-			.send()
-			.await
-			.map_err(|err| {
-				let code = err.status();
+	(
+		__req,
+		$url:expr,
+		$params:expr,
+		$client:expr,
+		$retry:expr,
+		$rate_limiter:expr,
+		$cache:expr,
+		$in_flight:expr
+	) => {
+		$crate::http::coalesce::send(
+			$client.get($url).query($params),
+			$retry,
+			$rate_limiter,
+			$cache,
+			$in_flight,
+		)
+		.await?
+	};
+
+	(__retry, $retry:expr) => {
+		$retry
+	};
 
-				if matches!(code, Some(::reqwest::StatusCode::NO_CONTENT)) {
-					return $crate::Error::EmptyResponse;
-				}
+	(__retry) => {
+		&$crate::http::RetryPolicy::none()
+	};
 
-				let message = err.to_string();
-				$crate::Error::Http { code, message }
-			})?
-			.error_for_status()
-			.map_err(|err| {
-				let code = err.status();
+	(__rate_limiter, $rate_limiter:expr) => {
+		$rate_limiter
+	};
 
-				if matches!(code, Some(::reqwest::StatusCode::NO_CONTENT)) {
-					return $crate::Error::EmptyResponse;
-				}
+	(__rate_limiter) => {
+		None
+	};
 
-				let message = err.to_string();
-				$crate::Error::Http { code, message }
-			})
-			.and_then(|response| {
-				if response.status() == ::reqwest::StatusCode::NO_CONTENT {
-					return Err($crate::Error::EmptyResponse);
-				}
+	(__cache, $cache:expr, $route:expr, $ttl:expr) => {
+		Some(($cache, $route, $ttl))
+	};
 
-				Ok(response)
-			})?
+	(__cache) => {
+		None
 	};
 
-	(__process, $request:expr, $type:ty) => {
-		// async {
-		// 	::serde_json::from_value::<$type>(
-		// 		dbg!(dbg!($request).json::<::serde_json::Value>().await.unwrap())
-		// 	)
-		// }
+	(__in_flight, $in_flight:expr) => {
+		$in_flight
+	};
 
-		$request.json::<$type>()
+	(__in_flight) => {
+		None
+	};
+
+	(__process, $request:expr, $type:ty) => {
+		$crate::http::json::<$type>($request)
 	};
 
 	(__process, $request:expr) => {
-		$request.text()
+		async { $request.text().await.map_err($crate::Error::from) }
 	};
 
 	(__finish, $response:expr) => {
-		$response.await.map_err(|err| {
-			$crate::Error::DeserializeResponse(err.to_string())
-		})
+		$response.await
 	};
 }
 
 pub(crate) use get;
 
+/// Sends a request with an arbitrary method and an optional JSON or multipart body.
+///
+/// Unlike [`get!`], responses are never cached or shared with other requests, and requests are
+/// only retried if a `retry` policy is passed explicitly.
+#[cfg(feature = "global-api")]
+macro_rules! request {
+	(
+		method = $method:ident;
+		url = $url:expr;
+		$( params = $params:expr; )?
+		$( body = $kind:ident($body:expr); )?
+		$( deserialize = $response:ty; )?
+		client = $client:expr;
+		$( retry = $retry:expr; )?
+		$( rate_limiter = $rate_limiter:expr; )?
+	) => {{
+		let params = $crate::http::get!(__params $(, $params)?);
+		let retry = $crate::http::get!(__retry $(, $retry)?);
+		let rate_limiter = $crate::http::get!(__rate_limiter $(, $rate_limiter)?);
+		let request = $client
+			.request(::reqwest::Method::$method, $url)
+			.query(params);
+
+		let request = $crate::http::request!(__body, request $(, $kind($body))?);
+		let response = $crate::http::retry::send(request, retry, rate_limiter).await?;
+		let response = $crate::http::get!(__process, response $(, $response)?);
+		$crate::http::get!(__finish, response)
+	}};
+
+	(__body, $request:expr) => {
+		$request
+	};
+
+	(__body, $request:expr, json($body:expr)) => {
+		$request.json($body)
+	};
+
+	(__body, $request:expr, multipart($body:expr)) => {
+		$request.multipart($body)
+	};
+}
+
+#[cfg(feature = "global-api")]
+pub(crate) use request;
+
 #[cfg(any(feature = "global-api", feature = "dawn-api"))]
 macro_rules! append_pairs {
 	($url:expr, $value:expr, $name:expr) => {{
@@ -96,3 +227,39 @@ macro_rules! append_pairs {
 
 #[cfg(any(feature = "global-api", feature = "dawn-api"))]
 pub(crate) use append_pairs;
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		crate::Tier,
+		::serde::Deserialize,
+		pretty_assertions::assert_eq,
+		reqwest::{ResponseBuilderExt, Url},
+	};
+
+	#[derive(Debug, Deserialize)]
+	#[allow(dead_code)]
+	struct Map {
+		difficulty: Tier,
+	}
+
+	#[tokio::test]
+	async fn json_reports_path() {
+		let body = r#"[{"difficulty":1},{"difficulty":2},{"difficulty":3},{"difficulty":8}]"#;
+		let response = ::http::Response::builder()
+			.url(Url::parse("https://kztimerglobal.com/api/v2.0/maps").unwrap())
+			.body(body)
+			.unwrap();
+
+		let Err(Error::Decode { url, serde_path, body: raw_body, .. }) =
+			json::<Vec<Map>>(Response::from(response)).await
+		else {
+			panic!("Expected a decode error.");
+		};
+
+		assert_eq!(url, "https://kztimerglobal.com/api/v2.0/maps");
+		assert_eq!(serde_path, "[3].difficulty");
+		assert_eq!(raw_body, body);
+	}
+}
@@ -0,0 +1,112 @@
+//! Automatic pagination for endpoints which take an `offset` and a `limit`.
+//!
+//! Every `*_with` endpoint that supports pagination has a `stream_*_with` counterpart which
+//! returns a [`Stream`](futures::Stream) of items instead of a single page. Pages are only fetched once the
+//! stream is polled past the end of the previous page.
+
+#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+use {
+	crate::{Error, Result},
+	futures::{stream, Stream, TryStreamExt},
+	std::future::Future,
+};
+
+/// Controls how a paginated stream fetches its items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pagination {
+	/// The amount of items requested per page.
+	pub page_size: u32,
+
+	/// The maximum amount of items the stream will yield in total.
+	pub max_items: Option<usize>,
+}
+
+impl Pagination {
+	/// The page size used by [`Pagination::default`].
+	pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+	/// Constructs a new [`Pagination`] with the default page size and no item limit.
+	pub const fn new() -> Self {
+		Self { page_size: Self::DEFAULT_PAGE_SIZE, max_items: None }
+	}
+
+	/// Sets the amount of items requested per page.
+	pub const fn page_size(mut self, page_size: u32) -> Self {
+		self.page_size = page_size;
+		self
+	}
+
+	/// Sets the maximum amount of items the stream will yield in total.
+	pub const fn max_items(mut self, max_items: usize) -> Self {
+		self.max_items = Some(max_items);
+		self
+	}
+}
+
+impl Default for Pagination {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+#[derive(Debug, Clone, Copy)]
+struct State {
+	offset: u32,
+	remaining: Option<usize>,
+	done: bool,
+}
+
+/// Turns a function fetching a single page into a [`Stream`] over all items.
+///
+/// `fetch_page` is called with an `offset` and a `limit`. Fetching stops once a page contains
+/// fewer than `limit` items, the API returns an empty response, or `max_items` have been yielded.
+#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+pub(crate) fn paginate<'a, T, F, Fut>(
+	offset: u32,
+	pagination: Pagination,
+	mut fetch_page: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+	T: 'a,
+	F: FnMut(u32, u32) -> Fut + 'a,
+	Fut: Future<Output = Result<Vec<T>>> + 'a, {
+	let page_size = pagination.page_size.max(1);
+	let state = State { offset, remaining: pagination.max_items, done: false };
+
+	stream::try_unfold(state, move |state| {
+		let page = (!state.done && state.remaining != Some(0)).then(|| {
+			let limit = state
+				.remaining
+				.map_or(page_size, |remaining| remaining.min(page_size as usize) as u32);
+
+			(limit, fetch_page(state.offset, limit))
+		});
+
+		async move {
+			let Some((limit, page)) = page else {
+				return Ok(None);
+			};
+
+			let items = match page.await {
+				Ok(items) => items,
+				Err(Error::EmptyResponse) => Vec::new(),
+				Err(err) => return Err(err),
+			};
+
+			let fetched = items.len().min(limit as usize);
+			let state = State {
+				offset: state.offset.saturating_add(fetched as u32),
+				remaining: state
+					.remaining
+					.map(|remaining| remaining.saturating_sub(fetched)),
+				done: fetched < limit as usize,
+			};
+
+			let items = items.into_iter().take(fetched).map(Ok);
+
+			Ok(Some((stream::iter(items), state)))
+		}
+	})
+	.try_flatten()
+}
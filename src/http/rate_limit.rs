@@ -0,0 +1,155 @@
+//! Client-side rate limiting.
+//!
+//! API clients configured with a [`RateLimit`] share a [`RateLimiter`] between all of their
+//! clones. Every request (including retries) waits for a token from the bucket of the host it is
+//! sent to, so callers never have to throttle themselves.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+/// How many requests may be sent to a single host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RateLimit {
+	/// The amount of requests per second once the burst has been used up.
+	pub requests_per_second: u32,
+
+	/// The amount of requests that can be sent at once before being throttled.
+	pub burst: u32,
+}
+
+impl RateLimit {
+	/// Constructs a new [`RateLimit`] with a burst size equal to `requests_per_second`.
+	pub const fn new(requests_per_second: u32) -> Self {
+		Self { requests_per_second, burst: requests_per_second }
+	}
+
+	/// Sets the amount of requests that can be sent at once before being throttled.
+	pub const fn burst(mut self, burst: u32) -> Self {
+		self.burst = burst;
+		self
+	}
+}
+
+/// A token bucket rate limiter with one bucket per host.
+///
+/// Cloning a [`RateLimiter`] is cheap and all clones share the same buckets.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+	rate_limit: RateLimit,
+	buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+	/// The amount of available tokens. This is negative if requests are already waiting.
+	tokens: f64,
+	last_refill: Instant,
+}
+
+impl RateLimiter {
+	/// Constructs a new [`RateLimiter`] with empty buckets.
+	pub fn new(rate_limit: RateLimit) -> Self {
+		Self { rate_limit, buckets: Arc::default() }
+	}
+
+	/// The [`RateLimit`] applied to every host.
+	pub fn rate_limit(&self) -> RateLimit {
+		self.rate_limit
+	}
+
+	/// Waits until a request to the given `host` is allowed to be sent.
+	pub async fn acquire(&self, host: &str) {
+		let delay = self.reserve(host);
+
+		if !delay.is_zero() {
+			tracing::trace!(?delay, host, "waiting for rate limit");
+			tokio::time::sleep(delay).await;
+		}
+	}
+
+	/// Blocks the current thread until a request to the given `host` is allowed to be sent.
+	#[cfg(all(
+		feature = "blocking",
+		any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api")
+	))]
+	pub(crate) fn wait(&self, host: &str) {
+		let delay = self.reserve(host);
+
+		if !delay.is_zero() {
+			tracing::trace!(?delay, host, "waiting for rate limit");
+			std::thread::sleep(delay);
+		}
+	}
+
+	/// Takes a token from the bucket of the given `host` and returns how long the caller has to
+	/// wait before it can use it.
+	fn reserve(&self, host: &str) -> Duration {
+		let rate = f64::from(self.rate_limit.requests_per_second.max(1));
+		let burst = f64::from(self.rate_limit.burst.max(1));
+		let now = Instant::now();
+
+		let mut buckets = self
+			.buckets
+			.lock()
+			.expect("The rate limiter lock is never held across a panic.");
+
+		let bucket = buckets
+			.entry(host.to_owned())
+			.or_insert(Bucket { tokens: burst, last_refill: now });
+
+		let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+		bucket.tokens = (bucket.tokens + elapsed * rate).min(burst) - 1.0;
+		bucket.last_refill = now;
+
+		if bucket.tokens >= 0.0 {
+			return Duration::ZERO;
+		}
+
+		Duration::from_secs_f64(-bucket.tokens / rate)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		crate::http::{retry, RetryPolicy},
+		pretty_assertions::assert_eq,
+		reqwest::multipart::{Form, Part},
+	};
+
+	#[test]
+	fn reserve() {
+		let rate_limiter = RateLimiter::new(RateLimit::new(10).burst(2));
+
+		assert_eq!(rate_limiter.reserve("kztimerglobal.com"), Duration::ZERO);
+		assert_eq!(rate_limiter.reserve("kztimerglobal.com"), Duration::ZERO);
+		assert_eq!(rate_limiter.reserve("kzgo.eu"), Duration::ZERO);
+
+		let delay = rate_limiter.reserve("kztimerglobal.com");
+		assert!(delay > Duration::ZERO && delay <= Duration::from_millis(100));
+
+		let delay = rate_limiter.reserve("kztimerglobal.com");
+		assert!(delay > Duration::from_millis(100) && delay <= Duration::from_millis(200));
+	}
+
+	#[tokio::test]
+	async fn streaming_requests_use_their_host() {
+		let rate_limiter = RateLimiter::new(RateLimit::new(10));
+		let form = Form::new().part("replay", Part::bytes(vec![]));
+		let request = reqwest::Client::new()
+			.post("http://127.0.0.1:9/records/1/replay")
+			.multipart(form);
+
+		assert!(request.try_clone().is_none());
+
+		// Nothing is listening on the discard port, so only the rate limiter's state matters.
+		let _ = retry::send(request, &RetryPolicy::none(), Some(&rate_limiter)).await;
+
+		let buckets = rate_limiter.buckets.lock().unwrap();
+		assert_eq!(buckets.keys().collect::<Vec<_>>(), ["127.0.0.1"]);
+	}
+}
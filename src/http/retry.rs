@@ -0,0 +1,254 @@
+//! Retrying failed requests.
+//!
+//! Every request made through one of the API clients in this crate is sent according to the
+//! client's [`RetryPolicy`]. Requests are only retried if the resulting error is
+//! [retryable](crate::Error::is_retryable).
+
+use {
+	super::RateLimiter,
+	crate::{Error, Result},
+	reqwest::{
+		header::{HeaderMap, RETRY_AFTER},
+		Client, Request, RequestBuilder, Response, StatusCode,
+	},
+	std::{
+		collections::hash_map::RandomState,
+		hash::{BuildHasher, Hasher},
+		time::Duration,
+	},
+	tracing::Instrument,
+};
+
+/// Controls how often and how fast failed requests are retried.
+///
+/// The delay before attempt `n` is `base_delay * 2^(n - 2)`, capped at `max_delay`. If the API
+/// sent a `Retry-After` header, that delay is used instead (still capped at `max_delay`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetryPolicy {
+	/// The maximum amount of attempts per request, including the first one.
+	pub max_attempts: u32,
+
+	/// The delay before the first retry.
+	pub base_delay: Duration,
+
+	/// The maximum delay between two attempts.
+	pub max_delay: Duration,
+
+	/// Whether to randomize each delay to avoid many clients retrying at the same time.
+	pub jitter: bool,
+}
+
+impl RetryPolicy {
+	/// Constructs a new [`RetryPolicy`] with 3 attempts, a base delay of 500ms, a max delay of
+	/// 30s and jitter enabled.
+	pub const fn new() -> Self {
+		Self {
+			max_attempts: 3,
+			base_delay: Duration::from_millis(500),
+			max_delay: Duration::from_secs(30),
+			jitter: true,
+		}
+	}
+
+	/// A [`RetryPolicy`] which never retries.
+	pub const fn none() -> Self {
+		Self { max_attempts: 1, ..Self::new() }
+	}
+
+	/// Sets the maximum amount of attempts per request, including the first one.
+	pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+		self.max_attempts = max_attempts;
+		self
+	}
+
+	/// Sets the delay before the first retry.
+	pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+		self.base_delay = base_delay;
+		self
+	}
+
+	/// Sets the maximum delay between two attempts.
+	pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+
+	/// Sets whether each delay should be randomized.
+	pub const fn jitter(mut self, jitter: bool) -> Self {
+		self.jitter = jitter;
+		self
+	}
+
+	/// Whether a response with the given status `code` should be retried.
+	///
+	/// This is the only place deciding which status codes are retried; see
+	/// [`Error::is_retryable`](crate::Error::is_retryable) for other kinds of errors.
+	pub fn is_retryable(code: StatusCode) -> bool {
+		matches!(
+			code,
+			StatusCode::TOO_MANY_REQUESTS
+				| StatusCode::INTERNAL_SERVER_ERROR
+				| StatusCode::BAD_GATEWAY
+				| StatusCode::SERVICE_UNAVAILABLE
+				| StatusCode::GATEWAY_TIMEOUT
+		)
+	}
+
+	/// The delay before the given `attempt` (starting at 1).
+	///
+	/// `retry_after` is the value of the `Retry-After` header of the previous response, if any.
+	pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+		if let Some(retry_after) = retry_after {
+			return retry_after.min(self.max_delay);
+		}
+
+		let factor = 2_u32.saturating_pow(attempt.saturating_sub(2));
+		let delay = self
+			.base_delay
+			.saturating_mul(factor)
+			.min(self.max_delay);
+
+		if !self.jitter {
+			return delay;
+		}
+
+		// Pick a random delay between 50% and 100% of the computed one.
+		let mut hasher = RandomState::new().build_hasher();
+		hasher.write_u32(attempt);
+		let random = (hasher.finish() % 1000) as u32;
+
+		delay / 2 + delay / 2 * random / 1000
+	}
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Parses the `Retry-After` header of a response. Only the "delay in seconds" form is supported.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+	headers
+		.get(RETRY_AFTER)?
+		.to_str()
+		.ok()?
+		.trim()
+		.parse::<u64>()
+		.ok()
+		.map(Duration::from_secs)
+}
+
+/// Sends the given `request`, retrying it according to `policy`.
+///
+/// If a `rate_limiter` is given, every attempt waits on it before being sent.
+pub(crate) async fn send(
+	request: RequestBuilder,
+	policy: &RetryPolicy,
+	rate_limiter: Option<&RateLimiter>,
+) -> Result<Response> {
+	let (client, request) = request.build_split();
+	let request = request?;
+	let max_attempts = policy.max_attempts.max(1);
+	let host = request
+		.url()
+		.host_str()
+		.unwrap_or_default()
+		.to_owned();
+
+	let mut retry_after = None;
+	let mut attempt = 1;
+
+	loop {
+		if attempt > 1 {
+			let delay = policy.delay(attempt, retry_after.take());
+			tracing::debug!(?delay, attempt, "retrying request");
+			tokio::time::sleep(delay).await;
+		}
+
+		if let Some(rate_limiter) = rate_limiter {
+			rate_limiter.acquire(&host).await;
+		}
+
+		let span = tracing::trace_span!("attempt", attempt, status = tracing::field::Empty);
+
+		// Requests with a streaming body cannot be cloned and therefore not be retried.
+		let Some(current) = request.try_clone() else {
+			return send_once(&client, request).instrument(span).await;
+		};
+
+		match send_once(&client, current).instrument(span).await {
+			Err(error) if error.is_retryable() && attempt < max_attempts => {
+				tracing::debug!(%error, attempt, "request failed");
+				retry_after = error.retry_after();
+			}
+			result => return result,
+		}
+
+		attempt += 1;
+	}
+}
+
+async fn send_once(client: &Client, request: Request) -> Result<Response> {
+	let response = client.execute(request).await?;
+	let status = response.status();
+	tracing::Span::current().record("status", status.as_u16());
+
+	if status == StatusCode::NO_CONTENT {
+		return Err(Error::EmptyResponse);
+	}
+
+	if let Err(err) = response.error_for_status_ref() {
+		return Err(match Error::from(err) {
+			Error::RateLimited { url, .. } => {
+				Error::RateLimited { url, retry_after: retry_after(response.headers()) }
+			}
+			error => error,
+		});
+	}
+
+	Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+	use {super::*, pretty_assertions::assert_eq};
+
+	#[test]
+	fn delay() {
+		let policy = RetryPolicy::new()
+			.base_delay(Duration::from_secs(1))
+			.max_delay(Duration::from_secs(5))
+			.jitter(false);
+
+		assert_eq!(policy.delay(2, None), Duration::from_secs(1));
+		assert_eq!(policy.delay(3, None), Duration::from_secs(2));
+		assert_eq!(policy.delay(4, None), Duration::from_secs(4));
+		assert_eq!(policy.delay(5, None), Duration::from_secs(5));
+		assert_eq!(policy.delay(2, Some(Duration::from_secs(3))), Duration::from_secs(3));
+		assert_eq!(policy.delay(2, Some(Duration::from_secs(60))), Duration::from_secs(5));
+
+		let policy = policy.jitter(true);
+
+		for attempt in 2..10 {
+			let delay = policy.delay(attempt, None);
+			let expected = policy.jitter(false).delay(attempt, None);
+			assert!(delay >= expected / 2 && delay <= expected);
+		}
+	}
+
+	#[test]
+	fn retryable_errors() {
+		let http = |code| Error::Http { code, message: String::new() };
+		let url = String::from("https://kztimerglobal.com/api/v2/records/top");
+
+		assert!(http(Some(StatusCode::BAD_GATEWAY)).is_retryable());
+		assert!(Error::RateLimited { url: url.clone(), retry_after: None }.is_retryable());
+		assert!(Error::Connection { url: url.clone(), message: String::new() }.is_retryable());
+		assert!(Error::Timeout { url: url.clone() }.is_retryable());
+
+		assert!(!http(Some(StatusCode::BAD_REQUEST)).is_retryable());
+		assert!(!http(None).is_retryable());
+		assert!(!Error::NotFound { url }.is_retryable());
+	}
+}
@@ -0,0 +1,181 @@
+//! This module holds an enum for jumpstat tiers. GOKZ colours jumpstat announcements based on
+//! which tier a jump's distance falls into. The threshold for each of these tiers depends on the
+//! jump type and the mode.
+//!
+//! GOKZ reads these thresholds from `cfg/sourcemod/gokz/gokz-jumpstats-tiers.cfg`, which server
+//! owners can edit. The defaults in [`JumpTier::thresholds`] have **not** been checked against a
+//! specific revision of that file yet, so compare them with the file on the servers you care
+//! about before relying on exact colours.
+//!
+//! GOKZ also has a sixth tier above [`JumpTier::Ownage`], "Wrecker". It is deliberately not
+//! represented here: its thresholds could not be sourced, so jumps that GOKZ would call a
+//! Wrecker are classified as [`JumpTier::Ownage`].
+//!
+//! Official documentation: <https://github.com/KZGlobalTeam/gokz/wiki/Jumpstats>
+
+use crate::{macros::is, JumpType, Mode};
+
+/// All GOKZ jumpstat tiers.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(rename_all = "snake_case", type_name = "JumpTier"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum JumpTier {
+	Meh,
+	Impressive,
+	Perfect,
+	Godlike,
+	Ownage,
+}
+
+impl JumpTier {
+	/// All tiers, from lowest to highest.
+	pub const ALL: [Self; 5] =
+		[Self::Meh, Self::Impressive, Self::Perfect, Self::Godlike, Self::Ownage];
+
+	/// The minimum distance for each tier (see [`JumpTier::ALL`]) for the given `jump_type` and
+	/// `mode`.
+	///
+	/// See the [module documentation](self) for where these values should come from.
+	pub const fn thresholds(jump_type: JumpType, mode: Mode) -> [f64; 5] {
+		match mode {
+			Mode::KZTimer => match jump_type {
+				JumpType::LongJump => [230.0, 250.0, 260.0, 265.0, 270.0],
+				JumpType::Bhop => [270.0, 300.0, 310.0, 320.0, 325.0],
+				JumpType::MultiBhop => [280.0, 310.0, 320.0, 330.0, 335.0],
+				JumpType::WeirdJump => [270.0, 290.0, 300.0, 305.0, 310.0],
+				JumpType::LadderJump => [120.0, 150.0, 160.0, 170.0, 175.0],
+				JumpType::Ladderhop => [270.0, 300.0, 310.0, 320.0, 325.0],
+				JumpType::Jumpbug => [270.0, 300.0, 310.0, 320.0, 325.0],
+				JumpType::LowpreBhop => [260.0, 285.0, 295.0, 305.0, 310.0],
+				JumpType::LowpreWeirdJump => [260.0, 280.0, 290.0, 295.0, 300.0],
+			},
+
+			Mode::SimpleKZ => match jump_type {
+				JumpType::LongJump => [230.0, 245.0, 255.0, 260.0, 265.0],
+				JumpType::Bhop => [270.0, 290.0, 300.0, 310.0, 315.0],
+				JumpType::MultiBhop => [280.0, 300.0, 310.0, 320.0, 325.0],
+				JumpType::WeirdJump => [260.0, 280.0, 290.0, 295.0, 300.0],
+				JumpType::LadderJump => [120.0, 145.0, 155.0, 165.0, 170.0],
+				JumpType::Ladderhop => [270.0, 290.0, 300.0, 310.0, 315.0],
+				JumpType::Jumpbug => [270.0, 290.0, 300.0, 310.0, 315.0],
+				JumpType::LowpreBhop => [260.0, 280.0, 290.0, 300.0, 305.0],
+				JumpType::LowpreWeirdJump => [250.0, 270.0, 280.0, 290.0, 295.0],
+			},
+
+			Mode::Vanilla => match jump_type {
+				JumpType::LongJump => [210.0, 230.0, 235.0, 240.0, 245.0],
+				JumpType::Bhop => [250.0, 270.0, 280.0, 285.0, 290.0],
+				JumpType::MultiBhop => [260.0, 280.0, 290.0, 295.0, 300.0],
+				JumpType::WeirdJump => [240.0, 260.0, 270.0, 275.0, 280.0],
+				JumpType::LadderJump => [110.0, 140.0, 150.0, 155.0, 160.0],
+				JumpType::Ladderhop => [250.0, 270.0, 280.0, 285.0, 290.0],
+				JumpType::Jumpbug => [250.0, 270.0, 280.0, 285.0, 290.0],
+				JumpType::LowpreBhop => [240.0, 260.0, 270.0, 275.0, 280.0],
+				JumpType::LowpreWeirdJump => [230.0, 250.0, 260.0, 265.0, 270.0],
+			},
+		}
+	}
+
+	/// Constructs a [`JumpTier`] from the given distance, jump type and mode.
+	///
+	/// Returns [`None`] if the distance is too short to be announced at all.
+	pub const fn from_distance(distance: f64, jump_type: JumpType, mode: Mode) -> Option<Self> {
+		let thresholds = Self::thresholds(jump_type, mode);
+		let mut tier = None;
+		let mut idx = 0;
+
+		while idx < thresholds.len() {
+			if distance >= thresholds[idx] {
+				tier = Some(Self::ALL[idx]);
+			}
+
+			idx += 1;
+		}
+
+		tier
+	}
+
+	/// The chat colour GOKZ uses for announcing jumps of this tier.
+	pub const fn colour(&self) -> &'static str {
+		match self {
+			JumpTier::Meh => "grey",
+			JumpTier::Impressive => "blue",
+			JumpTier::Perfect => "green",
+			JumpTier::Godlike => "darkred",
+			JumpTier::Ownage => "gold",
+		}
+	}
+
+	/// The RGB value of [`JumpTier::colour`], e.g. for Discord embeds.
+	pub const fn rgb(&self) -> u32 {
+		match self {
+			JumpTier::Meh => 0xCCCCCC,
+			JumpTier::Impressive => 0x5E98D9,
+			JumpTier::Perfect => 0x40FE40,
+			JumpTier::Godlike => 0x990000,
+			JumpTier::Ownage => 0xE4AE39,
+		}
+	}
+}
+
+#[rustfmt::skip]
+impl JumpTier {
+	is!(is_meh, Meh);
+	is!(is_impressive, Impressive);
+	is!(is_perfect, Perfect);
+	is!(is_godlike, Godlike);
+	is!(is_ownage, Ownage);
+}
+
+impl std::fmt::Display for JumpTier {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			JumpTier::Meh => "Meh",
+			JumpTier::Impressive => "Impressive",
+			JumpTier::Perfect => "Perfect",
+			JumpTier::Godlike => "Godlike",
+			JumpTier::Ownage => "Ownage",
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_distance() {
+		let lj = |distance| JumpTier::from_distance(distance, JumpType::LongJump, Mode::KZTimer);
+
+		assert_eq!(lj(200.0), None);
+		assert_eq!(lj(230.0), Some(JumpTier::Meh));
+		assert_eq!(lj(259.9999), Some(JumpTier::Impressive));
+		assert_eq!(lj(260.0), Some(JumpTier::Perfect));
+		assert_eq!(lj(268.0), Some(JumpTier::Godlike));
+		assert_eq!(lj(290.0), Some(JumpTier::Ownage));
+
+		assert_eq!(
+			JumpTier::from_distance(240.0, JumpType::LongJump, Mode::Vanilla),
+			Some(JumpTier::Godlike)
+		);
+	}
+
+	#[test]
+	fn thresholds_are_ascending() {
+		for mode in [Mode::KZTimer, Mode::SimpleKZ, Mode::Vanilla] {
+			for jump_type in (0..=8).map(|id| JumpType::try_from(id).unwrap()) {
+				let thresholds = JumpTier::thresholds(jump_type, mode);
+				assert!(
+					thresholds
+						.windows(2)
+						.all(|pair| pair[0] < pair[1]),
+					"{jump_type} {mode}"
+				);
+			}
+		}
+	}
+}
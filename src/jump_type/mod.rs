@@ -0,0 +1,214 @@
+//! This module contains an enum for the jump types tracked by GOKZ's jumpstats.
+//!
+//! Official documentation: <https://github.com/KZGlobalTeam/gokz/wiki/Jumpstats>
+
+use {
+	crate::{
+		macros::{
+			convert::{from, try_from},
+			is,
+		},
+		yeet,
+	},
+	std::str::FromStr,
+};
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(all(feature = "serde", test))]
+mod serde_tests;
+
+/// The 9 jump types tracked by GOKZ.
+///
+/// The discriminants are the ids GOKZ and the GlobalAPI use.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "JumpType"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub enum JumpType {
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "longjump"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "longjump"))]
+	LongJump = 0,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "bhop"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "bhop"))]
+	Bhop = 1,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "multibhop"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "multibhop"))]
+	MultiBhop = 2,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "weirdjump"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "weirdjump"))]
+	WeirdJump = 3,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "ladderjump"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "ladderjump"))]
+	LadderJump = 4,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "ladderhop"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "ladderhop"))]
+	Ladderhop = 5,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "jumpbug"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "jumpbug"))]
+	Jumpbug = 6,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "lowpre_bhop"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "lowpre_bhop"))]
+	LowpreBhop = 7,
+
+	#[cfg_attr(feature = "sqlx", sqlx(rename = "lowpre_weirdjump"))]
+	#[cfg_attr(feature = "utoipa", schema(rename = "lowpre_weirdjump"))]
+	LowpreWeirdJump = 8,
+}
+
+impl JumpType {
+	/// Format the given [`JumpType`] to be used in query parameters for the GlobalAPI.
+	pub const fn api(&self) -> &'static str {
+		match self {
+			JumpType::LongJump => "longjump",
+			JumpType::Bhop => "bhop",
+			JumpType::MultiBhop => "multibhop",
+			JumpType::WeirdJump => "weirdjump",
+			JumpType::LadderJump => "ladderjump",
+			JumpType::Ladderhop => "ladderhop",
+			JumpType::Jumpbug => "jumpbug",
+			JumpType::LowpreBhop => "lowpre_bhop",
+			JumpType::LowpreWeirdJump => "lowpre_weirdjump",
+		}
+	}
+
+	/// Shortened name for the given [`JumpType`], as displayed in-game.
+	pub const fn short(&self) -> &'static str {
+		match self {
+			JumpType::LongJump => "LJ",
+			JumpType::Bhop => "BH",
+			JumpType::MultiBhop => "MBH",
+			JumpType::WeirdJump => "WJ",
+			JumpType::LadderJump => "LAJ",
+			JumpType::Ladderhop => "LAH",
+			JumpType::Jumpbug => "JB",
+			JumpType::LowpreBhop => "LBH",
+			JumpType::LowpreWeirdJump => "LWJ",
+		}
+	}
+}
+
+#[rustfmt::skip]
+impl JumpType {
+	is!(is_longjump, LongJump);
+	is!(is_bhop, Bhop);
+	is!(is_multibhop, MultiBhop);
+	is!(is_weirdjump, WeirdJump);
+	is!(is_ladderjump, LadderJump);
+	is!(is_ladderhop, Ladderhop);
+	is!(is_jumpbug, Jumpbug);
+	is!(is_lowpre_bhop, LowpreBhop);
+	is!(is_lowpre_weirdjump, LowpreWeirdJump);
+}
+
+impl std::fmt::Display for JumpType {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{self:?}")
+	}
+}
+
+#[cfg(feature = "poise")]
+#[poise::async_trait]
+impl poise::SlashArgument for JumpType {
+	async fn extract(
+		_: &poise::serenity_prelude::Context,
+		_: poise::ApplicationCommandOrAutocompleteInteraction<'_>,
+		value: &poise::serenity_prelude::json::Value,
+	) -> Result<Self, poise::SlashArgError> {
+		let choice_key = value
+			.as_u64()
+			.ok_or(poise::SlashArgError::CommandStructureMismatch("expected u64"))?;
+
+		JumpType::try_from(choice_key)
+			.map_err(|_| poise::SlashArgError::CommandStructureMismatch("out of bounds choice key"))
+	}
+
+	fn create(builder: &mut poise::serenity_prelude::CreateApplicationCommandOption) {
+		builder.kind(poise::serenity_prelude::CommandOptionType::Integer);
+	}
+
+	fn choices() -> Vec<poise::CommandParameterChoice> {
+		[
+			"LongJump",
+			"Bhop",
+			"MultiBhop",
+			"WeirdJump",
+			"LadderJump",
+			"Ladderhop",
+			"Jumpbug",
+			"LowpreBhop",
+			"LowpreWeirdJump",
+		]
+		.into_iter()
+		.map(|name| poise::CommandParameterChoice {
+			name: String::from(name),
+			localizations: Default::default(),
+		})
+		.collect()
+	}
+}
+
+from!(JumpType => [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => |jump_type| {
+	jump_type as _
+});
+
+try_from!([i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize] => JumpType => |int| {
+	Ok(match int {
+		0 => JumpType::LongJump,
+		1 => JumpType::Bhop,
+		2 => JumpType::MultiBhop,
+		3 => JumpType::WeirdJump,
+		4 => JumpType::LadderJump,
+		5 => JumpType::Ladderhop,
+		6 => JumpType::Jumpbug,
+		7 => JumpType::LowpreBhop,
+		8 => JumpType::LowpreWeirdJump,
+		int => yeet!(InvalidJumpType(int)),
+	})
+});
+
+impl TryFrom<&str> for JumpType {
+	type Error = crate::Error;
+
+	fn try_from(input: &str) -> crate::Result<Self> {
+		FromStr::from_str(input)
+	}
+}
+
+impl TryFrom<String> for JumpType {
+	type Error = crate::Error;
+
+	fn try_from(input: String) -> crate::Result<Self> {
+		Self::try_from(input.as_str())
+	}
+}
+
+impl FromStr for JumpType {
+	type Err = crate::Error;
+
+	fn from_str(input: &str) -> crate::Result<Self> {
+		Ok(match input.to_lowercase().as_str() {
+			"0" | "longjump" | "long_jump" | "lj" => Self::LongJump,
+			"1" | "bhop" | "bh" => Self::Bhop,
+			"2" | "multibhop" | "multi_bhop" | "mbh" => Self::MultiBhop,
+			"3" | "weirdjump" | "weird_jump" | "wj" => Self::WeirdJump,
+			"4" | "ladderjump" | "ladder_jump" | "laj" => Self::LadderJump,
+			"5" | "ladderhop" | "ladder_hop" | "lah" => Self::Ladderhop,
+			"6" | "jumpbug" | "jump_bug" | "jb" => Self::Jumpbug,
+			"7" | "lowprebhop" | "lowpre_bhop" | "lbh" => Self::LowpreBhop,
+			"8" | "lowpreweirdjump" | "lowpre_weirdjump" | "lowpre_weird_jump" | "lwj" => {
+				Self::LowpreWeirdJump
+			}
+			_ => yeet!(InvalidJumpType(input)),
+		})
+	}
+}
@@ -0,0 +1,27 @@
+use {
+	super::JumpType,
+	serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+};
+
+impl Serialize for JumpType {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.api().serialize(serializer)
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Deserializable {
+	U8(u8),
+	String(String),
+}
+
+impl<'de> Deserialize<'de> for JumpType {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		match Deserializable::deserialize(deserializer)? {
+			Deserializable::U8(jump_type_id) => JumpType::try_from(jump_type_id),
+			Deserializable::String(jump_type_name) => JumpType::try_from(jump_type_name),
+		}
+		.map_err(|err| de::Error::custom(err.to_string()))
+	}
+}
@@ -0,0 +1,26 @@
+use {super::JumpType, pretty_assertions::assert_eq, serde_json::json};
+
+#[test]
+fn raw() {
+	let inputs = [
+		(json!("longjump"), JumpType::LongJump),
+		(json!("LJ"), JumpType::LongJump),
+		(json!(0), JumpType::LongJump),
+		(json!("2"), JumpType::MultiBhop),
+		(json!("ladder_hop"), JumpType::Ladderhop),
+		(json!(8), JumpType::LowpreWeirdJump),
+	];
+
+	for (i, (input, expected)) in inputs.into_iter().enumerate() {
+		let jump_type: JumpType =
+			serde_json::from_value(input).unwrap_or_else(|err| panic!("#{i}: {err:?}"));
+
+		assert_eq!(jump_type, expected, "#{i}");
+	}
+}
+
+#[test]
+fn serialize() {
+	assert_eq!(serde_json::to_value(JumpType::LowpreBhop).unwrap(), json!("lowpre_bhop"));
+	assert!(serde_json::from_value::<JumpType>(json!(9)).is_err());
+}
@@ -5,7 +5,7 @@
 //! - `/maps/name/:map_name`
 
 use {
-	super::API_URL,
+	super::KzgoApi,
 	crate::{http, yeet, Result, SteamID, Tier},
 	serde::{Deserialize, Serialize},
 };
@@ -52,32 +52,41 @@ pub struct Map {
 	pub date: String,
 }
 
-/// `/maps/:map_name` route
-///
-/// Fetches a single map by name.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_map(map_name: &str, client: &crate::http::Client) -> Result<Map> {
-	http::get! {
-		url = format!("{API_URL}/maps/{map_name}");
-		deserialize = Map;
-		client = client;
+impl KzgoApi {
+	/// `/maps/:map_name` route
+	///
+	/// Fetches a single map by name.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_map(&self, map_name: &str) -> Result<Map> {
+		http::get! {
+			url = format!("{}/maps/{map_name}", self.base_url());
+			deserialize = Map;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}
 	}
-}
 
-/// `/maps` route
-///
-/// Fetches all maps.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_maps(client: &crate::http::Client) -> Result<Vec<Map>> {
-	let maps = http::get! {
-		url = format!("{API_URL}/maps");
-		deserialize = Vec<Map>;
-		client = client;
-	}?;
+	/// `/maps` route
+	///
+	/// Fetches all maps.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_maps(&self) -> Result<Vec<Map>> {
+		let maps = http::get! {
+			url = format!("{}/maps", self.base_url());
+			deserialize = Vec<Map>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			cache = self.cache(), "/maps", std::time::Duration::from_secs(60 * 60);
+			in_flight = self.in_flight();
+		}?;
 
-	if maps.is_empty() {
-		yeet!(EmptyResponse);
-	}
+		if maps.is_empty() {
+			yeet!(EmptyResponse);
+		}
 
-	Ok(maps)
+		Ok(maps)
+	}
 }
@@ -0,0 +1,193 @@
+//! An in-process mock of [KZ:GO](https://kzgo.eu/)'s API.
+//!
+//! [`MockServer`] serves [`Fixtures`] over HTTP on a random local port. This makes it possible
+//! to test code using [`KzgoApi`] without network access.
+//!
+//! ```
+//! use gokz_rs::kzgo_api::mock::MockServer;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> gokz_rs::Result<()> {
+//! let mock_server = MockServer::start()?;
+//! let kzgo_api = mock_server.client();
+//!
+//! let map = kzgo_api.get_map("kz_lionharder").await?;
+//! assert_eq!(map.id, 992);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Fixtures are kept in the format KZ:GO responds with rather than as the types in
+//! [`kzgo_api`](super), since some of them (e.g. [`CompletionCount`](super::CompletionCount))
+//! serialize differently than KZ:GO sends them.
+
+use {
+	super::KzgoApi,
+	crate::{http, Mode, Result, SteamID},
+	axum::{
+		extract::{Path, State},
+		http::StatusCode,
+		routing::get,
+		Json, Router,
+	},
+	serde_json::Value as JsonValue,
+	std::{collections::HashMap, net::SocketAddr, sync::Arc},
+};
+
+/// The data served by a [`MockServer`], as raw KZ:GO responses.
+///
+/// [`Fixtures::default`] contains a small, fixed data set covering all endpoints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixtures {
+	/// Maps served by `/maps` and `/maps/:map_name`.
+	pub maps: Vec<JsonValue>,
+
+	/// The response served by `/servers`.
+	pub servers: JsonValue,
+
+	/// Completion counts served by `/completions/:mode`.
+	pub completions: HashMap<Mode, JsonValue>,
+
+	/// World records served by `/wrs/:mode`.
+	pub world_records: HashMap<Mode, Vec<JsonValue>>,
+
+	/// Users served by `/steam/:steam_id`.
+	pub users: HashMap<SteamID, JsonValue>,
+}
+
+impl Default for Fixtures {
+	fn default() -> Self {
+		Self {
+			maps: serde_json::from_str(include_str!("fixtures/maps.json"))
+				.expect("Bundled maps are valid."),
+			servers: serde_json::from_str(include_str!("fixtures/servers.json"))
+				.expect("Bundled servers are valid."),
+			completions: by_mode(include_str!("fixtures/completions.json")),
+			world_records: by_mode(include_str!("fixtures/world_records.json")),
+			users: serde_json::from_str::<HashMap<String, JsonValue>>(include_str!(
+				"fixtures/users.json"
+			))
+			.expect("Bundled users are valid.")
+			.into_iter()
+			.map(|(steam_id, user)| {
+				(steam_id.parse().expect("Bundled users have valid SteamIDs."), user)
+			})
+			.collect(),
+		}
+	}
+}
+
+/// Parses a bundled fixture keyed by [`Mode::api`].
+fn by_mode<T: serde::de::DeserializeOwned>(json: &str) -> HashMap<Mode, T> {
+	serde_json::from_str::<HashMap<String, T>>(json)
+		.expect("Bundled fixtures are valid.")
+		.into_iter()
+		.map(|(mode, value)| (mode.parse().expect("Bundled fixtures have valid modes."), value))
+		.collect()
+}
+
+/// A mock KZ:GO API running on its own thread.
+///
+/// The server shuts down when this value is dropped.
+#[derive(Debug)]
+pub struct MockServer {
+	server: http::mock::Server,
+}
+
+impl MockServer {
+	/// Starts a new server with the default [`Fixtures`].
+	pub fn start() -> Result<Self> {
+		Self::start_with(Fixtures::default())
+	}
+
+	/// Starts a new server with the given `fixtures`.
+	pub fn start_with(fixtures: Fixtures) -> Result<Self> {
+		Ok(Self { server: http::mock::Server::start(router(fixtures))? })
+	}
+
+	/// The address the server is listening on.
+	pub const fn addr(&self) -> SocketAddr {
+		self.server.addr()
+	}
+
+	/// The base URL to use for a [`KzgoApi`] talking to this server.
+	pub fn base_url(&self) -> String {
+		format!("http://{}", self.addr())
+	}
+
+	/// Returns a [`KzgoApi`] talking to this server.
+	pub fn client(&self) -> KzgoApi {
+		KzgoApi::builder()
+			.base_url(self.base_url())
+			.build()
+			.expect("The mock server's URL is valid.")
+	}
+}
+
+type Fixture = State<Arc<Fixtures>>;
+type Response = std::result::Result<Json<JsonValue>, StatusCode>;
+
+fn router(fixtures: Fixtures) -> Router {
+	Router::new()
+		.route("/maps", get(maps))
+		.route("/maps/:map_name", get(map))
+		.route("/servers", get(servers))
+		.route("/completions/:mode", get(completions))
+		.route("/wrs/:mode", get(world_records))
+		.route("/steam/:steam_id", get(user))
+		.with_state(Arc::new(fixtures))
+}
+
+fn parse_mode(mode: &str) -> std::result::Result<Mode, StatusCode> {
+	mode.parse().map_err(|_| StatusCode::BAD_REQUEST)
+}
+
+async fn maps(State(fixtures): Fixture) -> Response {
+	Ok(Json(JsonValue::from(fixtures.maps.clone())))
+}
+
+async fn map(State(fixtures): Fixture, Path(map_name): Path<String>) -> Response {
+	fixtures
+		.maps
+		.iter()
+		.find(|map| map["name"].as_str() == Some(map_name.as_str()))
+		.cloned()
+		.map(Json)
+		.ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn servers(State(fixtures): Fixture) -> Response {
+	Ok(Json(fixtures.servers.clone()))
+}
+
+async fn completions(State(fixtures): Fixture, Path(mode): Path<String>) -> Response {
+	fixtures
+		.completions
+		.get(&parse_mode(&mode)?)
+		.cloned()
+		.map(Json)
+		.ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn world_records(State(fixtures): Fixture, Path(mode): Path<String>) -> Response {
+	let world_records = fixtures
+		.world_records
+		.get(&parse_mode(&mode)?)
+		.cloned()
+		.unwrap_or_default();
+
+	Ok(Json(JsonValue::from(world_records)))
+}
+
+async fn user(State(fixtures): Fixture, Path(steam_id): Path<String>) -> Response {
+	let steam_id = steam_id
+		.parse::<SteamID>()
+		.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+	fixtures
+		.users
+		.get(&steam_id)
+		.cloned()
+		.map(Json)
+		.ok_or(StatusCode::NOT_FOUND)
+}
@@ -1,19 +1,29 @@
 //! This module contains various functions and submodules covering [KZ:GO](https://kzgo.eu/)'s API.
+//!
+//! All endpoints are exposed as methods on [`KzgoApi`].
 
 /// The base URL for all API requests.
 pub const API_URL: &str = "https://kzgo.eu/api";
 
+crate::http::api_client! {
+	/// A client for [KZ:GO](https://kzgo.eu/)'s API.
+	KzgoApi, KzgoApiBuilder, API_URL
+}
+
 pub mod maps;
-pub use maps::{get_map, get_maps, Map};
+pub use maps::Map;
 
 pub mod servers;
-pub use servers::{get_servers, Server};
+pub use servers::Server;
 
 pub mod completions;
-pub use completions::{get_completions, CompletionCount, CompletionStats};
+pub use completions::{CompletionCount, CompletionStats};
 
 pub mod world_records;
-pub use world_records::{get_world_records, WorldRecord};
+pub use world_records::WorldRecord;
 
 pub mod steam;
-pub use steam::{get_user, User};
+pub use steam::User;
+
+#[cfg(feature = "mock")]
+pub mod mock;
@@ -1,7 +1,7 @@
 //! `/servers` endpoint
 
 use {
-	super::API_URL,
+	super::KzgoApi,
 	crate::{http, Result, Tier},
 	serde::{Deserialize, Serialize},
 	std::net::{IpAddr, SocketAddr},
@@ -74,15 +74,20 @@ impl Server {
 	}
 }
 
-/// `/servers` route
-///
-/// Fetches all servers.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_servers(client: &crate::http::Client) -> Result<Vec<Server>> {
-	http::get! {
-		url = format!("{API_URL}/servers");
-		deserialize = ServerStates;
-		client = client;
+impl KzgoApi {
+	/// `/servers` route
+	///
+	/// Fetches all servers.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_servers(&self) -> Result<Vec<Server>> {
+		http::get! {
+			url = format!("{}/servers", self.base_url());
+			deserialize = ServerStates;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}
+		.map(|result| result.server_states)
 	}
-	.map(|result| result.server_states)
 }
@@ -1,7 +1,7 @@
 //! `/steam` endpoint
 
 use {
-	super::API_URL,
+	super::KzgoApi,
 	crate::{http, Result, SteamID},
 	serde::{Deserialize, Serialize},
 };
@@ -16,17 +16,19 @@ pub struct User {
 	pub country: String,
 }
 
-/// `/steam/:steam_id` route
-///
-/// Fetches information about a player by [`SteamID`].
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_user(
-	steam_id: impl Into<SteamID> + std::fmt::Debug,
-	client: &crate::http::Client,
-) -> Result<User> {
-	http::get! {
-		url = format!("{API_URL}/steam/{}", steam_id.into().as_id64());
-		deserialize = User;
-		client = client;
+impl KzgoApi {
+	/// `/steam/:steam_id` route
+	///
+	/// Fetches information about a player by [`SteamID`].
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_user(&self, steam_id: impl Into<SteamID> + std::fmt::Debug) -> Result<User> {
+		http::get! {
+			url = format!("{}/steam/{}", self.base_url(), steam_id.into().as_id64());
+			deserialize = User;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}
 	}
 }
@@ -1,7 +1,7 @@
 //! `/wrs/:mode` endpoint
 
 use {
-	super::API_URL,
+	super::KzgoApi,
 	crate::{http, Mode, Result, SteamID},
 	serde::{Deserialize, Serialize},
 };
@@ -34,17 +34,22 @@ pub struct WorldRecord {
 	pub created_on: String,
 }
 
-/// `/wrs/:mode_name` route
-///
-/// Fetches all world records for the given mode.
-#[tracing::instrument(level = "TRACE", skip(client))]
-pub async fn get_world_records(
-	mode: impl Into<Mode> + std::fmt::Debug,
-	client: &crate::http::Client,
-) -> Result<Vec<WorldRecord>> {
-	http::get! {
-		url = format!("{API_URL}/wrs/{}", mode.into().api());
-		deserialize = Vec<WorldRecord>;
-		client = client;
+impl KzgoApi {
+	/// `/wrs/:mode_name` route
+	///
+	/// Fetches all world records for the given mode.
+	#[tracing::instrument(level = "TRACE", skip(self))]
+	pub async fn get_world_records(
+		&self,
+		mode: impl Into<Mode> + std::fmt::Debug,
+	) -> Result<Vec<WorldRecord>> {
+		http::get! {
+			url = format!("{}/wrs/{}", self.base_url(), mode.into().api());
+			deserialize = Vec<WorldRecord>;
+			client = self.client();
+			retry = self.retry_policy();
+			rate_limiter = self.rate_limiter();
+			in_flight = self.in_flight();
+		}
 	}
 }
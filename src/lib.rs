@@ -22,6 +22,12 @@ pub use runtype::Runtype;
 pub mod tier;
 pub use tier::Tier;
 
+pub mod jump_type;
+pub use jump_type::JumpType;
+
+pub mod jump_tier;
+pub use jump_tier::JumpTier;
+
 mod identifier;
 pub(crate) use identifier::identifier;
 
@@ -34,6 +40,13 @@ pub use server_identifier::ServerIdentifier;
 pub mod player_identifier;
 pub use player_identifier::PlayerIdentifier;
 
+pub mod completion;
+
+#[cfg(any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api"))]
+pub mod profile;
+
+pub mod replay;
+
 pub mod prelude;
 pub mod util;
 
@@ -52,6 +65,15 @@ pub mod kzgo_api;
 #[cfg(feature = "dawn-api")]
 pub mod dawn_api;
 
+#[cfg(any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api"))]
+pub mod backend;
+
+#[cfg(all(
+	feature = "blocking",
+	any(feature = "global-api", feature = "kzgo-api", feature = "dawn-api")
+))]
+pub mod blocking;
+
 pub(crate) mod macros;
 
 #[cfg(test)]
@@ -9,6 +9,8 @@
 
 pub use crate::{
 	error::{Error, Result},
+	jump_tier::JumpTier,
+	jump_type::JumpType,
 	map_identifier::MapIdentifier,
 	mode::Mode,
 	player_identifier::PlayerIdentifier,
@@ -0,0 +1,416 @@
+//! This module aggregates everything needed to render a player's profile from all available
+//! APIs.
+//!
+//! Every API is optional, so a profile can be built from whichever backends are compiled in and
+//! passed to [`PlayerProfile::fetch`]. The player and map tiers are fetched through
+//! [`KzBackend`]. The GlobalAPI is the primary source for records, points and world records. The
+//! KZ:GO API provides the player's avatar and country, and DawnAPI is used as a fallback for the
+//! player's name, completions and latest records if the GlobalAPI is unavailable. Failing requests
+//! do not fail the whole profile; they are collected in [`PlayerProfile::failures`] instead.
+//!
+//! ```no_run
+//! # #[cfg(feature = "global-api")]
+//! # mod example {
+//! use gokz_rs::{global_api::GlobalApi, profile::{Backends, PlayerProfile}, Mode, SteamID};
+//!
+//! async fn run() -> gokz_rs::Result<()> {
+//!     let global_api = GlobalApi::builder().build()?;
+//!     let backends = Backends { global_api: Some(&global_api), ..Default::default() };
+//!
+//!     let steam_id = SteamID::try_from(76561198282622073_u64)?;
+//!     let profile = PlayerProfile::fetch(steam_id, backends).await?;
+//!
+//!     println!("{} is {:?} in SimpleKZ", profile.name, profile.rank(Mode::SimpleKZ));
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::{
+	backend::{KzBackend, Player, Record},
+	yeet, Error, Mode, Rank, Result, Runtype, SteamID,
+};
+
+#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+use crate::Tier;
+
+#[cfg(feature = "global-api")]
+use {
+	crate::{
+		global_api::{records, GlobalApi},
+		http::Pagination,
+	},
+	futures::{future, TryStreamExt},
+	std::collections::HashMap,
+};
+
+#[cfg(feature = "kzgo-api")]
+use crate::kzgo_api::KzgoApi;
+
+#[cfg(feature = "dawn-api")]
+use crate::dawn_api::DawnApi;
+
+/// The amount of records kept in [`PlayerProfile::latest_records`].
+pub const LATEST_RECORDS: usize = 10;
+
+/// The amount of records requested per page when fetching a player's records.
+#[cfg(feature = "global-api")]
+pub const RECORDS_PAGE_SIZE: u32 = 1000;
+
+const MODES: [Mode; 3] = [Mode::KZTimer, Mode::SimpleKZ, Mode::Vanilla];
+const RUNTYPES: [Runtype; 2] = [Runtype::Pro, Runtype::TP];
+
+/// The API clients [`PlayerProfile::fetch`] may use.
+///
+/// Every backend is optional; the more are available, the more complete the profile will be.
+#[allow(missing_docs)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Backends<'a> {
+	#[cfg(feature = "global-api")]
+	pub global_api: Option<&'a GlobalApi>,
+
+	#[cfg(feature = "kzgo-api")]
+	pub kzgo_api: Option<&'a KzgoApi>,
+
+	#[cfg(feature = "dawn-api")]
+	pub dawn_api: Option<&'a DawnApi>,
+}
+
+/// The API a [`PlayerProfile::failures`] entry came from.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Backend {
+	GlobalApi,
+	KzgoApi,
+	DawnApi,
+}
+
+/// A player's statistics for a single mode and runtype.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+	#[allow(missing_docs)]
+	pub mode: Mode,
+
+	#[allow(missing_docs)]
+	pub runtype: Runtype,
+
+	/// The total amount of completed maps.
+	pub completions: u32,
+
+	/// The amount of completed maps per [`Tier`](crate::Tier), starting at
+	/// [`Tier::VeryEasy`](crate::Tier::VeryEasy).
+	pub completions_by_tier: [u32; 7],
+
+	/// The sum of points of all personal bests, if the GlobalAPI was available.
+	pub points: Option<u32>,
+
+	/// The amount of world records, if the GlobalAPI was available.
+	pub world_records: Option<u32>,
+}
+
+impl Stats {
+	fn new(mode: Mode, runtype: Runtype) -> Self {
+		Self {
+			mode,
+			runtype,
+			completions: 0,
+			completions_by_tier: [0; 7],
+			points: None,
+			world_records: None,
+		}
+	}
+
+	#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+	fn complete(&mut self, tier: Option<Tier>) {
+		self.completions += 1;
+
+		if let Some(tier) = tier {
+			self.completions_by_tier[tier as usize - 1] += 1;
+		}
+	}
+}
+
+/// Everything needed to render a player's profile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerProfile {
+	#[allow(missing_docs)]
+	pub steam_id: SteamID,
+
+	#[allow(missing_docs)]
+	pub name: String,
+
+	/// The player's Steam avatar, if the KZ:GO API was available.
+	pub avatar_url: Option<String>,
+
+	/// The player's country, if the KZ:GO API was available.
+	pub country: Option<String>,
+
+	#[allow(missing_docs)]
+	pub is_banned: bool,
+
+	/// One entry for every mode and runtype.
+	pub stats: Vec<Stats>,
+
+	/// The player's most recent personal bests on the main course, newest first. Only available
+	/// if the GlobalAPI or DawnAPI was available.
+	pub latest_records: Vec<Record>,
+
+	/// Every request that failed while building this profile.
+	pub failures: Vec<(Backend, Error)>,
+}
+
+impl PlayerProfile {
+	/// Builds a profile for the player with the given `steam_id` from all available
+	/// `backends`.
+	///
+	/// This only fails if none of the backends could find the player at all.
+	#[tracing::instrument(level = "TRACE", skip(backends))]
+	pub async fn fetch(steam_id: SteamID, backends: Backends<'_>) -> Result<Self> {
+		let mut profile = PlayerProfile {
+			steam_id,
+			name: String::new(),
+			avatar_url: None,
+			country: None,
+			is_banned: false,
+			stats: MODES
+				.into_iter()
+				.flat_map(|mode| RUNTYPES.map(|runtype| Stats::new(mode, runtype)))
+				.collect(),
+			latest_records: Vec::new(),
+			failures: Vec::new(),
+		};
+
+		#[cfg(feature = "global-api")]
+		if let Some(global_api) = backends.global_api {
+			let (player, records, tiers) = future::join3(
+				KzBackend::get_player(global_api, steam_id.into()),
+				global_records(global_api, steam_id),
+				global_tiers(global_api),
+			)
+			.await;
+
+			profile.add_player(Backend::GlobalApi, player);
+
+			let tiers = tiers.unwrap_or_else(|error| {
+				profile.failures.push((Backend::GlobalApi, error));
+				HashMap::new()
+			});
+
+			match records {
+				Ok(records) => {
+					match global_world_records(global_api, &records).await {
+						Ok(world_records) => {
+							for (stats, count) in profile.stats.iter_mut().zip(world_records) {
+								stats.world_records = Some(count);
+							}
+						}
+						Err(error) => profile.failures.push((Backend::GlobalApi, error)),
+					}
+
+					profile.add_global_records(records, &tiers);
+				}
+				Err(error) => profile.failures.push((Backend::GlobalApi, error)),
+			}
+		}
+
+		#[cfg(feature = "kzgo-api")]
+		if let Some(kzgo_api) = backends.kzgo_api {
+			let player = KzBackend::get_player(kzgo_api, steam_id.into()).await;
+			profile.add_player(Backend::KzgoApi, player);
+		}
+
+		#[cfg(feature = "dawn-api")]
+		if let Some(dawn_api) = backends
+			.dawn_api
+			.filter(|_| !profile.is_found() || !profile.has_global_records())
+		{
+			let (player, records) = futures::future::join(
+				KzBackend::get_player(dawn_api, steam_id.into()),
+				dawn_api.get_pbs(steam_id, 0),
+			)
+			.await;
+
+			profile.add_player(Backend::DawnApi, player);
+
+			match records {
+				Ok(records) if !profile.has_global_records() => {
+					for record in &records {
+						profile
+							.stats_mut(record.mode, record.runtype())
+							.complete(record.stage_tier);
+					}
+
+					profile.add_latest_records(records.into_iter().map(Into::into));
+				}
+				Ok(_) => {}
+				Err(error) if error.is_not_found() => {}
+				Err(error) => profile.failures.push((Backend::DawnApi, error)),
+			}
+		}
+
+		if !profile.is_found() {
+			let Some((_, error)) = profile.failures.into_iter().next() else {
+				yeet!(EmptyResponse);
+			};
+
+			return Err(error);
+		}
+
+		Ok(profile)
+	}
+
+	/// The player's statistics for the given `mode` and `runtype`.
+	pub fn stats(&self, mode: Mode, runtype: Runtype) -> &Stats {
+		&self.stats[stats_index(mode, runtype)]
+	}
+
+	/// The player's total points in the given `mode`, if the GlobalAPI was available.
+	pub fn points(&self, mode: Mode) -> Option<u32> {
+		RUNTYPES
+			.into_iter()
+			.map(|runtype| self.stats(mode, runtype).points)
+			.sum()
+	}
+
+	/// The player's [`Rank`] in the given `mode`, if the GlobalAPI was available.
+	pub fn rank(&self, mode: Mode) -> Option<Rank> {
+		self.points(mode)
+			.map(|points| Rank::from_points(points, mode))
+	}
+
+	fn is_found(&self) -> bool {
+		!self.name.is_empty()
+	}
+
+	/// Fills in everything about the player that is still missing. Earlier backends take
+	/// precedence.
+	fn add_player(&mut self, backend: Backend, player: Result<Player>) {
+		let player = match player {
+			Ok(player) => player,
+			Err(error) => {
+				self.failures.push((backend, error));
+				return;
+			}
+		};
+
+		if !self.is_found() {
+			self.name = player.name;
+			self.is_banned = player.is_banned.unwrap_or_default();
+		}
+
+		self.avatar_url = self.avatar_url.take().or(player.avatar_url);
+		self.country = self.country.take().or(player.country);
+	}
+
+	#[cfg(feature = "dawn-api")]
+	fn has_global_records(&self) -> bool {
+		self.stats
+			.iter()
+			.any(|stats| stats.points.is_some())
+	}
+
+	#[cfg(feature = "dawn-api")]
+	fn stats_mut(&mut self, mode: Mode, runtype: Runtype) -> &mut Stats {
+		&mut self.stats[stats_index(mode, runtype)]
+	}
+
+	#[cfg(feature = "global-api")]
+	fn add_global_records(&mut self, records: Vec<Vec<Record>>, tiers: &HashMap<u16, Tier>) {
+		for (idx, records) in records.into_iter().enumerate() {
+			let stats = &mut self.stats[idx];
+			stats.points = Some(
+				records
+					.iter()
+					.filter_map(|record| record.points)
+					.sum(),
+			);
+
+			for record in &records {
+				stats.complete(tiers.get(&record.map_id).copied());
+			}
+
+			self.add_latest_records(records);
+		}
+	}
+
+	#[cfg(any(feature = "global-api", feature = "dawn-api"))]
+	fn add_latest_records(&mut self, records: impl IntoIterator<Item = Record>) {
+		self.latest_records.extend(records);
+		self.latest_records
+			.sort_by(|a, b| a.created_on.cmp(&b.created_on).reverse());
+		self.latest_records.truncate(LATEST_RECORDS);
+	}
+}
+
+const fn stats_index(mode: Mode, runtype: Runtype) -> usize {
+	let mode = match mode {
+		Mode::KZTimer => 0,
+		Mode::SimpleKZ => 1,
+		Mode::Vanilla => 2,
+	};
+
+	let runtype = match runtype {
+		Runtype::Pro => 0,
+		Runtype::TP => 1,
+	};
+
+	mode * RUNTYPES.len() + runtype
+}
+
+/// Fetches all of the player's main course personal bests for every mode and runtype, in the
+/// same order as [`PlayerProfile::stats`].
+#[cfg(feature = "global-api")]
+async fn global_records(global_api: &GlobalApi, steam_id: SteamID) -> Result<Vec<Vec<Record>>> {
+	let requests = MODES.into_iter().flat_map(|mode| {
+		RUNTYPES.map(|runtype| {
+			let params = records::Params {
+				steam_id: Some(steam_id),
+				stage: Some(0),
+				mode: Some(mode),
+				runtype: Some(runtype),
+				..Default::default()
+			};
+
+			global_api
+				.stream_records_with(&params, Pagination::new().page_size(RECORDS_PAGE_SIZE))
+				.map_ok(Record::from)
+				.try_collect::<Vec<_>>()
+		})
+	});
+
+	future::try_join_all(requests).await
+}
+
+/// Counts the world records among the player's personal `records` (as returned by
+/// [`global_records`]).
+///
+/// The GlobalAPI gives world records the maximum of 1000 points, so only those personal bests are
+/// candidates. Their leaderboard place is checked to confirm they are still the world record.
+#[cfg(feature = "global-api")]
+async fn global_world_records(global_api: &GlobalApi, records: &[Vec<Record>]) -> Result<Vec<u32>> {
+	let requests = records.iter().map(|records| async move {
+		let places = records
+			.iter()
+			.filter(|record| record.points == Some(1000))
+			.map(|record| global_api.get_place(record.id));
+
+		let places = future::try_join_all(places).await?;
+
+		Ok::<_, Error>(places.into_iter().filter(|&place| place == 1).count() as u32)
+	});
+
+	future::try_join_all(requests).await
+}
+
+/// Fetches the tier of every global map.
+#[cfg(feature = "global-api")]
+async fn global_tiers(global_api: &GlobalApi) -> Result<HashMap<u16, Tier>> {
+	let maps = KzBackend::get_maps(global_api).await?;
+
+	Ok(maps
+		.into_iter()
+		.map(|map| (map.id, map.tier))
+		.collect())
+}
@@ -1,7 +1,11 @@
 //! This module holds an enum for player ranks. Based on points each player will have a rank
-//! assigned to them. The threshold for each of these ranks may also depend on the mode.
+//! assigned to them. The threshold for each of these ranks may also depend on the mode, see
+//! [`Rank::thresholds`].
 
-use crate::{macros::is, Mode};
+use {
+	crate::{macros::is, yeet, Mode},
+	std::str::FromStr,
+};
 
 /// All GOKZ player ranks.
 #[allow(missing_docs)]
@@ -50,107 +54,145 @@ pub enum Rank {
 }
 
 impl Rank {
+	/// All ranks, from lowest to highest.
+	pub const ALL: [Self; 23] = [
+		Self::New,
+		Self::BeginnerMinus,
+		Self::Beginner,
+		Self::BeginnerPlus,
+		Self::AmateurMinus,
+		Self::Amateur,
+		Self::AmateurPlus,
+		Self::CasualMinus,
+		Self::Casual,
+		Self::CasualPlus,
+		Self::RegularMinus,
+		Self::Regular,
+		Self::RegularPlus,
+		Self::SkilledMinus,
+		Self::Skilled,
+		Self::SkilledPlus,
+		Self::ExpertMinus,
+		Self::Expert,
+		Self::ExpertPlus,
+		Self::Semipro,
+		Self::Pro,
+		Self::Master,
+		Self::Legend,
+	];
+
+	/// The minimum amount of points for each rank (see [`Rank::ALL`]) in the given `mode`.
+	pub const fn thresholds(mode: Mode) -> [u32; 23] {
+		match mode {
+			Mode::KZTimer => [
+				0, 1, 500, 1_000, //
+				2_000, 5_000, 10_000, //
+				20_000, 30_000, 40_000, //
+				60_000, 70_000, 80_000, //
+				100_000, 120_000, 150_000, //
+				200_000, 230_000, 250_000, //
+				400_000, 600_000, 800_000, 1_000_000,
+			],
+
+			Mode::SimpleKZ => [
+				0, 1, 500, 1_000, //
+				2_000, 5_000, 10_000, //
+				20_000, 30_000, 40_000, //
+				60_000, 70_000, 80_000, //
+				100_000, 120_000, 150_000, //
+				200_000, 230_000, 250_000, //
+				300_000, 400_000, 500_000, 800_000,
+			],
+
+			Mode::Vanilla => [
+				0, 1, 500, 1_000, //
+				2_000, 5_000, 10_000, //
+				20_000, 30_000, 40_000, //
+				60_000, 70_000, 80_000, //
+				100_000, 120_000, 140_000, //
+				160_000, 180_000, 200_000, //
+				250_000, 300_000, 400_000, 600_000,
+			],
+		}
+	}
+
 	/// Constructs a [`Rank`] from the given amount of points and mode.
 	pub const fn from_points(points: u32, mode: Mode) -> Self {
-		match mode {
-			Mode::KZTimer => match points {
-				1_000_000.. => Self::Legend,
-				800_000.. => Self::Master,
-				600_000.. => Self::Pro,
-				400_000.. => Self::Semipro,
-
-				250_000.. => Self::ExpertPlus,
-				230_000.. => Self::Expert,
-				200_000.. => Self::ExpertMinus,
-
-				150_000.. => Self::SkilledPlus,
-				120_000.. => Self::Skilled,
-				100_000.. => Self::SkilledMinus,
-
-				80_000.. => Self::RegularPlus,
-				70_000.. => Self::Regular,
-				60_000.. => Self::RegularMinus,
-
-				40_000.. => Self::CasualPlus,
-				30_000.. => Self::Casual,
-				20_000.. => Self::CasualMinus,
-
-				10_000.. => Self::AmateurPlus,
-				5_000.. => Self::Amateur,
-				2_000.. => Self::AmateurMinus,
-
-				1_000.. => Self::BeginnerPlus,
-				500.. => Self::Beginner,
-				1.. => Self::BeginnerMinus,
-
-				0 => Self::New,
-			},
-
-			Mode::SimpleKZ => match points {
-				800_000.. => Self::Legend,
-				500_000.. => Self::Master,
-				400_000.. => Self::Pro,
-				300_000.. => Self::Semipro,
-
-				250_000.. => Self::ExpertPlus,
-				230_000.. => Self::Expert,
-				200_000.. => Self::ExpertMinus,
-
-				150_000.. => Self::SkilledPlus,
-				120_000.. => Self::Skilled,
-				100_000.. => Self::SkilledMinus,
-
-				80_000.. => Self::RegularPlus,
-				70_000.. => Self::Regular,
-				60_000.. => Self::RegularMinus,
-
-				40_000.. => Self::CasualPlus,
-				30_000.. => Self::Casual,
-				20_000.. => Self::CasualMinus,
-
-				10_000.. => Self::AmateurPlus,
-				5_000.. => Self::Amateur,
-				2_000.. => Self::AmateurMinus,
-
-				1_000.. => Self::BeginnerPlus,
-				500.. => Self::Beginner,
-				1.. => Self::BeginnerMinus,
-
-				0 => Self::New,
-			},
-
-			Mode::Vanilla => match points {
-				600_000.. => Self::Legend,
-				400_000.. => Self::Master,
-				300_000.. => Self::Pro,
-				250_000.. => Self::Semipro,
-
-				200_000.. => Self::ExpertPlus,
-				180_000.. => Self::Expert,
-				160_000.. => Self::ExpertMinus,
-
-				140_000.. => Self::SkilledPlus,
-				120_000.. => Self::Skilled,
-				100_000.. => Self::SkilledMinus,
-
-				80_000.. => Self::RegularPlus,
-				70_000.. => Self::Regular,
-				60_000.. => Self::RegularMinus,
-
-				40_000.. => Self::CasualPlus,
-				30_000.. => Self::Casual,
-				20_000.. => Self::CasualMinus,
-
-				10_000.. => Self::AmateurPlus,
-				5_000.. => Self::Amateur,
-				2_000.. => Self::AmateurMinus,
-
-				1_000.. => Self::BeginnerPlus,
-				500.. => Self::Beginner,
-				1.. => Self::BeginnerMinus,
-
-				0.. => Self::New,
-			},
+		let thresholds = Self::thresholds(mode);
+		let mut idx = thresholds.len() - 1;
+
+		while thresholds[idx] > points {
+			idx -= 1;
+		}
+
+		Self::ALL[idx]
+	}
+
+	/// The minimum amount of points required for this rank in the given `mode`.
+	pub const fn min_points(&self, mode: Mode) -> u32 {
+		Self::thresholds(mode)[*self as usize]
+	}
+
+	/// The rank after this one, if any.
+	pub const fn next(&self) -> Option<Self> {
+		match *self as usize + 1 {
+			idx if idx < Self::ALL.len() => Some(Self::ALL[idx]),
+			_ => None,
+		}
+	}
+
+	/// The rank before this one, if any.
+	pub const fn previous(&self) -> Option<Self> {
+		match *self as usize {
+			0 => None,
+			idx => Some(Self::ALL[idx - 1]),
+		}
+	}
+
+	/// Calculates how far a player with the given amount of `points` is from reaching their next
+	/// rank.
+	///
+	/// Returns the current rank, the next rank, the amount of points missing for the next rank
+	/// and how much of the way there the player is as a fraction (`0.0..=1.0`). Players who
+	/// already reached the highest rank are always at `(Rank::Legend, None, 0, 1.0)`.
+	pub fn progress(points: u32, mode: Mode) -> (Self, Option<Self>, u32, f64) {
+		let current = Self::from_points(points, mode);
+		let Some(next) = current.next() else {
+			return (current, None, 0, 1.0);
+		};
+
+		let (min, next_min) = (current.min_points(mode), next.min_points(mode));
+		let fraction = f64::from(points - min) / f64::from(next_min - min);
+
+		(current, Some(next), next_min - points, fraction)
+	}
+
+	/// The name of this rank as it is displayed in-game, e.g. `Beginner-` or `Expert+`.
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Rank::New => "New",
+			Rank::BeginnerMinus => "Beginner-",
+			Rank::Beginner => "Beginner",
+			Rank::BeginnerPlus => "Beginner+",
+			Rank::AmateurMinus => "Amateur-",
+			Rank::Amateur => "Amateur",
+			Rank::AmateurPlus => "Amateur+",
+			Rank::CasualMinus => "Casual-",
+			Rank::Casual => "Casual",
+			Rank::CasualPlus => "Casual+",
+			Rank::RegularMinus => "Regular-",
+			Rank::Regular => "Regular",
+			Rank::RegularPlus => "Regular+",
+			Rank::SkilledMinus => "Skilled-",
+			Rank::Skilled => "Skilled",
+			Rank::SkilledPlus => "Skilled+",
+			Rank::ExpertMinus => "Expert-",
+			Rank::Expert => "Expert",
+			Rank::ExpertPlus => "Expert+",
+			Rank::Semipro => "Semipro",
+			Rank::Pro => "Pro",
+			Rank::Master => "Master",
+			Rank::Legend => "Legend",
 		}
 	}
 }
@@ -181,3 +223,91 @@ impl Rank {
 	is!(is_master, Master);
 	is!(is_legend, Legend);
 }
+
+impl std::fmt::Display for Rank {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+impl TryFrom<&str> for Rank {
+	type Error = crate::Error;
+
+	fn try_from(input: &str) -> crate::Result<Self> {
+		FromStr::from_str(input)
+	}
+}
+
+impl TryFrom<String> for Rank {
+	type Error = crate::Error;
+
+	fn try_from(input: String) -> crate::Result<Self> {
+		Self::try_from(input.as_str())
+	}
+}
+
+impl FromStr for Rank {
+	type Err = crate::Error;
+
+	/// Parses the names used in-game (e.g. `Expert+`) as well as the variant names (e.g.
+	/// `ExpertPlus`), ignoring case.
+	fn from_str(input: &str) -> crate::Result<Self> {
+		let Some(rank) = Self::ALL.into_iter().find(|rank| {
+			rank.name().eq_ignore_ascii_case(input)
+				|| format!("{rank:?}").eq_ignore_ascii_case(input)
+		}) else {
+			yeet!(InvalidRank(input));
+		};
+
+		Ok(rank)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use {super::*, pretty_assertions::assert_eq};
+
+	#[test]
+	fn from_points() {
+		assert_eq!(Rank::from_points(0, Mode::KZTimer), Rank::New);
+		assert_eq!(Rank::from_points(1, Mode::KZTimer), Rank::BeginnerMinus);
+		assert_eq!(Rank::from_points(299_999, Mode::SimpleKZ), Rank::ExpertPlus);
+		assert_eq!(Rank::from_points(300_000, Mode::SimpleKZ), Rank::Semipro);
+		assert_eq!(Rank::from_points(u32::MAX, Mode::Vanilla), Rank::Legend);
+
+		for mode in [Mode::KZTimer, Mode::SimpleKZ, Mode::Vanilla] {
+			for rank in Rank::ALL {
+				assert_eq!(Rank::from_points(rank.min_points(mode), mode), rank);
+			}
+		}
+	}
+
+	#[test]
+	fn progress() {
+		assert_eq!(
+			Rank::progress(75_000, Mode::KZTimer),
+			(Rank::Regular, Some(Rank::RegularPlus), 5_000, 0.5)
+		);
+
+		assert_eq!(
+			Rank::progress(0, Mode::Vanilla),
+			(Rank::New, Some(Rank::BeginnerMinus), 1, 0.0)
+		);
+
+		assert_eq!(Rank::progress(2_000_000, Mode::KZTimer), (Rank::Legend, None, 0, 1.0));
+	}
+
+	#[test]
+	fn names() {
+		for rank in Rank::ALL {
+			assert_eq!(rank.to_string().parse::<Rank>().unwrap(), rank);
+			assert_eq!(format!("{rank:?}").parse::<Rank>().unwrap(), rank);
+		}
+
+		assert_eq!("expert+".parse::<Rank>().unwrap(), Rank::ExpertPlus);
+		assert_eq!(Rank::Legend.next(), None);
+		assert_eq!(Rank::New.previous(), None);
+		assert_eq!(Rank::Casual.next(), Some(Rank::CasualPlus));
+		assert!("Expert++".parse::<Rank>().is_err());
+	}
+}
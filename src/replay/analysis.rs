@@ -0,0 +1,366 @@
+//! Jump statistics derived from the tick data of a [`Replay`].
+//!
+//! [`jumps`] finds every time the player left the ground and landed again, and computes the
+//! same statistics GOKZ shows for jumpstats. The numbers are computed from recorded positions
+//! and view angles only, so they may differ slightly from what the plugin reported in-game.
+
+use {
+	super::{Format, Replay, TickData},
+	crate::JumpType,
+};
+
+/// `FL_ONGROUND`
+pub const FL_ONGROUND: u32 = 1 << 0;
+
+/// `IN_JUMP`
+pub const IN_JUMP: u32 = 1 << 1;
+
+/// The width of the player's bounding box, which GOKZ adds to every jump distance.
+pub const PLAYER_WIDTH: f64 = 32.0;
+
+/// The maximum amount of ticks a player can spend on the ground between two jumps for the second
+/// one to count as a bhop.
+pub const BHOP_GROUND_TICKS: usize = 2;
+
+/// The tickrate assumed for version 1 replays, which don't store it.
+pub const DEFAULT_TICKRATE: f64 = 128.0;
+
+/// The different kinds of jumps.
+///
+/// See [`JumpKind::jump_type`] for the matching [`JumpType`], e.g. to rate a jump with
+/// [`JumpTier::from_distance`](crate::JumpTier::from_distance).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JumpKind {
+	/// A jump after standing or walking on the ground.
+	LongJump,
+
+	/// A jump right after landing from a jump.
+	Bhop,
+
+	/// A bhop right after landing from another bhop.
+	MultiBhop,
+
+	/// Leaving the ground without jumping, e.g. by walking off a ledge.
+	Fall,
+}
+
+impl JumpKind {
+	/// The [`JumpType`] GOKZ would record this jump as, if any.
+	///
+	/// Falls are not jumpstats, so [`JumpKind::Fall`] returns [`None`].
+	pub const fn jump_type(&self) -> Option<JumpType> {
+		match self {
+			JumpKind::LongJump => Some(JumpType::LongJump),
+			JumpKind::Bhop => Some(JumpType::Bhop),
+			JumpKind::MultiBhop => Some(JumpType::MultiBhop),
+			JumpKind::Fall => None,
+		}
+	}
+}
+
+/// Statistics for a single jump.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jump {
+	/// What kind of jump this was.
+	pub kind: JumpKind,
+
+	/// The index of the last tick on the ground before the jump.
+	pub takeoff_tick: usize,
+
+	/// The index of the first tick on the ground after the jump.
+	pub landing_tick: usize,
+
+	/// The horizontal distance between takeoff and landing, including [`PLAYER_WIDTH`].
+	pub distance: f64,
+
+	/// How much higher (positive) or lower (negative) the player landed.
+	pub height_difference: f64,
+
+	/// The amount of times the player changed turning direction mid-air.
+	pub strafes: usize,
+
+	/// The percentage (`0..=100`) of ticks in the air during which the player gained speed.
+	pub sync: f64,
+
+	/// The total amount of speed gained in the air.
+	pub gains: f64,
+
+	/// The amount of ticks spent in the air.
+	pub airtime: usize,
+
+	/// The horizontal speed at takeoff.
+	pub pre_speed: f64,
+
+	/// The highest horizontal speed reached in the air.
+	pub max_speed: f64,
+}
+
+impl Jump {
+	/// The time spent in the air in seconds.
+	pub fn airtime_seconds(&self, tickrate: f64) -> f64 {
+		self.airtime as f64 / tickrate
+	}
+}
+
+/// The tickrate the given `replay` was recorded at.
+pub fn tickrate(replay: &Replay) -> f64 {
+	match replay.format {
+		Format::V1 { .. } => DEFAULT_TICKRATE,
+		Format::V2 { tickrate, .. } => f64::from(tickrate),
+	}
+}
+
+/// The player's horizontal speed at every tick of the given `replay`.
+///
+/// The speed is derived from the change in position since the previous tick, since version 1
+/// replays don't store velocities.
+pub fn speeds(replay: &Replay) -> Vec<f64> {
+	let tickrate = tickrate(replay);
+	let mut previous = None::<&TickData>;
+
+	replay
+		.ticks
+		.iter()
+		.map(|tick| {
+			let speed = previous.map_or(0.0, |previous| {
+				let dx = f64::from(tick.origin[0] - previous.origin[0]);
+				let dy = f64::from(tick.origin[1] - previous.origin[1]);
+				dx.hypot(dy) * tickrate
+			});
+
+			previous = Some(tick);
+			speed
+		})
+		.collect()
+}
+
+/// Finds every jump in the given `replay`.
+///
+/// A jump starts on the last tick the player is on the ground and ends on the first tick they
+/// are on the ground again. Jumps that haven't landed by the end of the replay are ignored.
+pub fn jumps(replay: &Replay) -> Vec<Jump> {
+	let ticks = &replay.ticks;
+	let speeds = speeds(replay);
+	let on_ground = |index: usize| ticks[index].flags & FL_ONGROUND != 0;
+
+	let mut jumps = Vec::<Jump>::new();
+	let mut last_landing = None::<usize>;
+	let mut index = 1;
+
+	while index < ticks.len() {
+		if !on_ground(index - 1) || on_ground(index) {
+			index += 1;
+			continue;
+		}
+
+		let takeoff_tick = index - 1;
+		let Some(landing_tick) = (index..ticks.len()).find(|&index| on_ground(index)) else {
+			break;
+		};
+
+		let jumped = ticks[takeoff_tick..=index]
+			.iter()
+			.any(|tick| tick.buttons & IN_JUMP != 0);
+
+		let is_bhop =
+			last_landing.is_some_and(|landing| takeoff_tick - landing < BHOP_GROUND_TICKS);
+
+		let previous_kind = jumps
+			.last()
+			.filter(|_| is_bhop)
+			.map(|jump| jump.kind);
+
+		let kind = match (jumped, previous_kind) {
+			(false, _) => JumpKind::Fall,
+			(true, None) => JumpKind::LongJump,
+			(true, Some(JumpKind::LongJump | JumpKind::Fall)) => JumpKind::Bhop,
+			(true, Some(JumpKind::Bhop | JumpKind::MultiBhop)) => JumpKind::MultiBhop,
+		};
+
+		jumps.push(jump(ticks, &speeds, kind, takeoff_tick, landing_tick));
+		last_landing = Some(landing_tick);
+		index = landing_tick + 1;
+	}
+
+	jumps
+}
+
+fn jump(
+	ticks: &[TickData],
+	speeds: &[f64],
+	kind: JumpKind,
+	takeoff_tick: usize,
+	landing_tick: usize,
+) -> Jump {
+	let (takeoff, landing) = (ticks[takeoff_tick].origin, ticks[landing_tick].origin);
+	let dx = f64::from(landing[0] - takeoff[0]);
+	let dy = f64::from(landing[1] - takeoff[1]);
+
+	let air = takeoff_tick + 1..landing_tick;
+	let airtime = air.len();
+
+	let mut strafes = 0;
+	let mut turning_left = None::<bool>;
+	let mut gaining_ticks = 0;
+	let mut gains = 0.0;
+	let mut max_speed = speeds[takeoff_tick];
+
+	for index in air {
+		let turn = yaw_delta(ticks[index - 1].angles[1], ticks[index].angles[1]);
+
+		if turn != 0.0 && turning_left != Some(turn > 0.0) {
+			strafes += 1;
+			turning_left = Some(turn > 0.0);
+		}
+
+		let gain = speeds[index] - speeds[index - 1];
+
+		if gain > 0.0 {
+			gaining_ticks += 1;
+			gains += gain;
+		}
+
+		max_speed = max_speed.max(speeds[index]);
+	}
+
+	Jump {
+		kind,
+		takeoff_tick,
+		landing_tick,
+		distance: dx.hypot(dy) + PLAYER_WIDTH,
+		height_difference: f64::from(landing[2] - takeoff[2]),
+		strafes,
+		sync: if airtime == 0 { 0.0 } else { gaining_ticks as f64 / airtime as f64 * 100.0 },
+		gains,
+		airtime,
+		pre_speed: speeds[takeoff_tick],
+		max_speed,
+	}
+}
+
+/// The difference between two yaw angles, normalized to `-180..180`.
+pub(super) fn yaw_delta(from: f32, to: f32) -> f64 {
+	(f64::from(to) - f64::from(from) + 180.0).rem_euclid(360.0) - 180.0
+}
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		pretty_assertions::assert_eq,
+	};
+
+	const FIXTURE: &[u8] = include_bytes!("fixtures/v1_run.replay");
+
+	/// A replay where the player walks at 2 units per tick and jumps at the given ticks.
+	///
+	/// Each jump lasts `air` ticks; the player turns left and right every 4 ticks in the air and
+	/// gains speed while turning left.
+	fn jump_replay(takeoffs: &[usize], air: usize, len: usize) -> Replay {
+		let mut x = 0.0;
+		let mut ticks = Vec::new();
+		let mut airborne_until = 0;
+		let mut yaw = 0.0;
+
+		for index in 0..len {
+			let jumping = takeoffs.contains(&index);
+			if jumping {
+				airborne_until = index + air;
+			}
+
+			let in_air = index > 0 && index <= airborne_until && !jumping;
+			let turning_left = in_air && (index / 4) % 2 == 0;
+
+			if in_air {
+				yaw += if turning_left { 1.0 } else { -1.0 };
+			}
+
+			x += if turning_left { 2.5 } else { 2.0 };
+
+			ticks.push(TickData {
+				origin: [x, 0.0, 0.0],
+				angles: [0.0, yaw, 0.0],
+				buttons: if jumping { IN_JUMP } else { 0 },
+				flags: if in_air { 0 } else { FL_ONGROUND },
+				..Default::default()
+			});
+		}
+
+		crate::replay::tests::replay(ticks)
+	}
+
+	#[test]
+	fn long_jump() {
+		let jumps = jumps(&jump_replay(&[10], 16, 40));
+
+		assert_eq!(jumps.len(), 1);
+
+		let jump = jumps[0];
+		assert_eq!(jump.kind, JumpKind::LongJump);
+		assert_eq!((jump.takeoff_tick, jump.landing_tick), (10, 27));
+		assert_eq!(jump.airtime, 16);
+		assert_eq!(jump.strafes, 5);
+		assert_eq!(jump.pre_speed, 2.0 * DEFAULT_TICKRATE);
+		assert_eq!(jump.max_speed, 2.5 * DEFAULT_TICKRATE);
+		assert!(jump.sync > 0.0 && jump.sync < 100.0);
+		assert!(jump.gains > 0.0);
+		assert!(jump.distance > PLAYER_WIDTH + 16.0 * 2.0);
+		assert_eq!(jump.airtime_seconds(DEFAULT_TICKRATE), 0.125);
+	}
+
+	#[test]
+	fn bhops() {
+		let kinds = jumps(&jump_replay(&[10, 28, 46, 80], 16, 120))
+			.into_iter()
+			.map(|jump| jump.kind)
+			.collect::<Vec<_>>();
+
+		assert_eq!(kinds, [
+			JumpKind::LongJump,
+			JumpKind::Bhop,
+			JumpKind::MultiBhop,
+			JumpKind::LongJump
+		]);
+
+		let jump_types = kinds
+			.into_iter()
+			.map(|kind| kind.jump_type())
+			.collect::<Vec<_>>();
+
+		assert_eq!(jump_types, [
+			Some(JumpType::LongJump),
+			Some(JumpType::Bhop),
+			Some(JumpType::MultiBhop),
+			Some(JumpType::LongJump)
+		]);
+	}
+
+	#[test]
+	fn falls_and_unfinished_jumps() {
+		let mut replay = jump_replay(&[10, 40], 16, 50);
+		replay.ticks[10].buttons = 0;
+
+		let jumps = jumps(&replay);
+		assert_eq!(jumps.len(), 1);
+		assert_eq!(jumps[0].kind, JumpKind::Fall);
+		assert_eq!(jumps[0].kind.jump_type(), None);
+	}
+
+	#[test]
+	fn fixture() {
+		let replay = Replay::parse(FIXTURE).unwrap();
+		let jumps = jumps(&replay);
+
+		assert_eq!(jumps.len(), 7);
+
+		for jump in jumps {
+			assert_eq!(jump.kind, JumpKind::LongJump);
+			assert_eq!(jump.airtime, 4);
+			assert_eq!(jump.distance, 5.0 * 2.5 + PLAYER_WIDTH);
+			assert_eq!(jump.strafes, 1);
+			assert_eq!(jump.sync, 0.0);
+		}
+	}
+}
@@ -0,0 +1,415 @@
+//! Heuristics for spotting cheated runs in a [`Replay`].
+//!
+//! Each heuristic looks for one pattern in the tick data that is very unlikely to come from a
+//! human and reports it as a [`Suspicion`] tagged with the matching [`BanType`]:
+//!
+//! - long streaks of perfect bhops ([`BanType::BhopHack`])
+//! - jump inputs that are spaced out exactly the same way every time ([`BanType::BhopMacro`])
+//! - near perfect strafe sync over many jumps ([`BanType::StrafeHack`])
+//! - turning at the same rate for many ticks ([`BanType::StrafeMacro`])
+//!
+//! None of these are proof of cheating; they are meant to help admins decide which replays to
+//! watch first.
+//!
+//! ```no_run
+//! use gokz_rs::replay::{anticheat, Replay};
+//!
+//! # fn main() -> gokz_rs::Result<()> {
+//! let replay = Replay::from_file("kz_lionharder_0_SKZ_NRM_PRO.replay")?;
+//!
+//! for suspicion in anticheat::analyze(&replay) {
+//!     println!("{:?} ({:.2}): {}", suspicion.ban_type, suspicion.score, suspicion.reason);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use {
+	super::{
+		analysis::{self, FL_ONGROUND, IN_JUMP},
+		Replay,
+	},
+	crate::global_api::bans::BanType,
+};
+
+/// The amount of consecutive perfect bhops after which a streak becomes suspicious.
+pub const PERFECT_BHOP_STREAK: usize = 10;
+
+/// The minimum amount of jump inputs needed to judge their regularity.
+pub const MIN_SCROLL_SAMPLES: usize = 20;
+
+/// The amount of ticks before a bhop in which jump inputs are collected.
+pub const SCROLL_WINDOW: usize = 16;
+
+/// The minimum amount of jumps with strafes needed to judge the player's sync.
+pub const MIN_SYNC_SAMPLES: usize = 5;
+
+/// The average sync (in percent) above which a player's strafes become suspicious.
+pub const SYNC_THRESHOLD: f64 = 90.0;
+
+/// The amount of consecutive ticks of turning at the same rate after which it becomes suspicious.
+pub const IDENTICAL_TURN_STREAK: usize = 16;
+
+/// The largest difference (in degrees) between two yaw changes for them to count as identical.
+///
+/// View angles are stored as `f32`, so the same turn rate can produce slightly different deltas
+/// depending on the angle it starts from.
+pub const TURN_EPSILON: f64 = 1e-4;
+
+/// A pattern in a [`Replay`] that points to a cheat.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Suspicion {
+	/// The kind of cheat this pattern points to.
+	pub ban_type: BanType,
+
+	/// How suspicious the pattern is, from `0.0` (barely) to `1.0` (almost certainly a cheat).
+	pub score: f64,
+
+	/// The tick at which the pattern starts, to know where to look when watching the replay.
+	pub tick: usize,
+
+	/// A human readable description of what was found.
+	pub reason: String,
+}
+
+/// Runs every heuristic on the given `replay` and returns the suspicions found, most suspicious
+/// first.
+pub fn analyze(replay: &Replay) -> Vec<Suspicion> {
+	let mut suspicions = [
+		perfect_bhops(replay),
+		scroll_pattern(replay),
+		strafe_sync(replay),
+		identical_turns(replay),
+	]
+	.into_iter()
+	.flatten()
+	.collect::<Vec<_>>();
+
+	suspicions.sort_by(|a, b| b.score.total_cmp(&a.score));
+	suspicions
+}
+
+/// Looks for long streaks of bhops on the very first tick on the ground.
+pub fn perfect_bhops(replay: &Replay) -> Option<Suspicion> {
+	let jumps = analysis::jumps(replay);
+	let mut longest = (0, 0);
+	let mut current = (0, 0);
+
+	for (previous, jump) in jumps.iter().zip(jumps.iter().skip(1)) {
+		if jump.takeoff_tick == previous.landing_tick {
+			if current.0 == 0 {
+				current.1 = jump.takeoff_tick;
+			}
+
+			current.0 += 1;
+		} else {
+			current.0 = 0;
+		}
+
+		if current.0 > longest.0 {
+			longest = current;
+		}
+	}
+
+	let (streak, tick) = longest;
+
+	(streak >= PERFECT_BHOP_STREAK).then(|| Suspicion {
+		ban_type: BanType::BhopHack,
+		score: (streak as f64 / (PERFECT_BHOP_STREAK * 2) as f64).min(1.0),
+		tick,
+		reason: format!("{streak} perfect bhops in a row"),
+	})
+}
+
+/// Looks for jump inputs before bhops that are always spaced out the same way.
+///
+/// Humans scrolling their mouse wheel produce irregular gaps between inputs; macros don't.
+pub fn scroll_pattern(replay: &Replay) -> Option<Suspicion> {
+	let ticks = &replay.ticks;
+	let jumps = analysis::jumps(replay);
+	let mut gaps = Vec::new();
+	let mut first_tick = None;
+
+	for (previous, jump) in jumps.iter().zip(jumps.iter().skip(1)) {
+		if jump.takeoff_tick - previous.landing_tick >= analysis::BHOP_GROUND_TICKS {
+			continue;
+		}
+
+		let window = jump.takeoff_tick.saturating_sub(SCROLL_WINDOW)..=jump.takeoff_tick + 1;
+		let presses = window
+			.filter(|&index| index > 0 && index < ticks.len())
+			.filter(|&index| {
+				ticks[index].buttons & IN_JUMP != 0 && ticks[index - 1].buttons & IN_JUMP == 0
+			})
+			.collect::<Vec<_>>();
+
+		gaps.extend(presses.windows(2).map(|presses| (presses[1] - presses[0]) as f64));
+		first_tick.get_or_insert(jump.takeoff_tick);
+	}
+
+	if gaps.len() < MIN_SCROLL_SAMPLES {
+		return None;
+	}
+
+	let mean = gaps.iter().sum::<f64>() / gaps.len() as f64;
+	let variance = gaps
+		.iter()
+		.map(|gap| (gap - mean).powi(2))
+		.sum::<f64>()
+		/ gaps.len() as f64;
+
+	// The coefficient of variation; `0` means every gap is exactly the same.
+	let variation = variance.sqrt() / mean;
+	let score = 1.0 - variation / 0.1;
+
+	(score > 0.0).then(|| Suspicion {
+		ban_type: BanType::BhopMacro,
+		score,
+		tick: first_tick.unwrap_or_default(),
+		reason: format!(
+			"{} jump inputs spaced {mean:.1} ticks apart with {:.1}% variation",
+			gaps.len() + 1,
+			variation * 100.0
+		),
+	})
+}
+
+/// Looks for consistently near perfect sync over many jumps.
+pub fn strafe_sync(replay: &Replay) -> Option<Suspicion> {
+	let jumps = analysis::jumps(replay)
+		.into_iter()
+		.filter(|jump| jump.strafes >= 2)
+		.collect::<Vec<_>>();
+
+	if jumps.len() < MIN_SYNC_SAMPLES {
+		return None;
+	}
+
+	let average = jumps.iter().map(|jump| jump.sync).sum::<f64>() / jumps.len() as f64;
+
+	(average >= SYNC_THRESHOLD).then(|| Suspicion {
+		ban_type: BanType::StrafeHack,
+		score: ((average - SYNC_THRESHOLD) / (100.0 - SYNC_THRESHOLD)).clamp(0.1, 1.0),
+		tick: jumps[0].takeoff_tick,
+		reason: format!("{average:.1}% average sync over {} jumps", jumps.len()),
+	})
+}
+
+/// Looks for turning at the same rate (within [`TURN_EPSILON`]) for many ticks in the air.
+///
+/// Mouse movement is never perfectly even, so identical view angle changes over many ticks mean
+/// the angles were set by a program.
+pub fn identical_turns(replay: &Replay) -> Option<Suspicion> {
+	let ticks = &replay.ticks;
+	let mut longest = (0, 0);
+	let mut current = (0, 0);
+	let mut previous_turn = 0.0;
+
+	for index in 1..ticks.len() {
+		let in_air = ticks[index].flags & FL_ONGROUND == 0;
+		let turn = analysis::yaw_delta(ticks[index - 1].angles[1], ticks[index].angles[1]);
+
+		let same_turn = turn.abs() > TURN_EPSILON && (turn - previous_turn).abs() <= TURN_EPSILON;
+
+		if in_air && same_turn {
+			if current.0 == 0 {
+				current.1 = index - 1;
+			}
+
+			current.0 += 1;
+		} else {
+			current.0 = 0;
+		}
+
+		if current.0 > longest.0 {
+			longest = current;
+		}
+
+		previous_turn = turn;
+	}
+
+	// The first tick of a streak has nothing to be compared against.
+	let (streak, tick) = (longest.0 + 1, longest.1);
+
+	(streak >= IDENTICAL_TURN_STREAK).then(|| Suspicion {
+		ban_type: BanType::StrafeMacro,
+		score: (streak as f64 / (IDENTICAL_TURN_STREAK * 4) as f64).min(1.0),
+		tick,
+		reason: format!("turned at the same rate for {streak} ticks"),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		crate::replay::TickData,
+		pretty_assertions::assert_eq,
+	};
+
+	/// A replay of `bhops` bhops, each lasting `air` ticks, with `ground` ticks on the ground in
+	/// between. `turn` returns the yaw change for a tick in the air and `scroll` whether jump is
+	/// held on a tick before takeoff.
+	fn bhop_replay(
+		bhops: usize,
+		air: usize,
+		ground: usize,
+		turn: impl Fn(usize) -> f32,
+		scroll: impl Fn(usize) -> bool,
+	) -> Replay {
+		let mut ticks = vec![TickData { flags: FL_ONGROUND, ..Default::default() }; 32];
+		let mut yaw = 0.0;
+
+		for _ in 0..bhops {
+			let takeoff = ticks.len() - 1;
+			ticks[takeoff].buttons |= IN_JUMP;
+
+			for index in 0..air {
+				yaw += turn(index);
+				let x = ticks.len() as f32 * (2.0 + (index % 8) as f32 * 0.01);
+				let buttons = if air - index <= SCROLL_WINDOW && scroll(air - index) {
+					IN_JUMP
+				} else {
+					0
+				};
+
+				ticks.push(TickData {
+					origin: [x, 0.0, 0.0],
+					angles: [0.0, yaw, 0.0],
+					buttons,
+					..Default::default()
+				});
+			}
+
+			for _ in 0..=ground {
+				let x = ticks.len() as f32 * 2.0;
+				ticks.push(TickData {
+					origin: [x, 0.0, 0.0],
+					angles: [0.0, yaw, 0.0],
+					flags: FL_ONGROUND,
+					..Default::default()
+				});
+			}
+		}
+
+		crate::replay::tests::replay(ticks)
+	}
+
+	/// Irregular, human looking mouse movement.
+	fn human_turn(index: usize) -> f32 {
+		[1.0, -1.0][index / 12 % 2] * (0.5 + (index * 7 % 5) as f32 * 0.3)
+	}
+
+	/// Perfectly even mouse movement, switching direction halfway through a 48 tick jump.
+	fn macro_turn(index: usize) -> f32 {
+		if index < 24 { 1.5 } else { -1.5 }
+	}
+
+	/// Irregular, human looking scrolling.
+	fn human_scroll(ticks_before_takeoff: usize) -> bool {
+		matches!(ticks_before_takeoff, 3 | 6 | 7 | 9 | 12 | 15)
+	}
+
+	#[test]
+	fn perfect_bhops() {
+		let cheated = bhop_replay(24, 48, 0, human_turn, human_scroll);
+		let suspicion = super::perfect_bhops(&cheated).unwrap();
+
+		assert_eq!(suspicion.ban_type, BanType::BhopHack);
+		assert_eq!(suspicion.score, 1.0);
+
+		let legit = bhop_replay(24, 48, 3, human_turn, human_scroll);
+		assert_eq!(super::perfect_bhops(&legit), None);
+	}
+
+	#[test]
+	fn scroll_pattern() {
+		let cheated = bhop_replay(12, 48, 0, human_turn, |ticks| ticks % 2 == 0);
+		let suspicion = super::scroll_pattern(&cheated).unwrap();
+
+		assert_eq!(suspicion.ban_type, BanType::BhopMacro);
+		assert_eq!(suspicion.score, 1.0);
+
+		let legit = bhop_replay(12, 48, 0, human_turn, human_scroll);
+		assert_eq!(super::scroll_pattern(&legit), None);
+	}
+
+	#[test]
+	fn strafe_sync() {
+		let legit = bhop_replay(8, 48, 4, human_turn, human_scroll);
+		assert_eq!(super::strafe_sync(&legit), None);
+
+		// Gain speed on every single tick in the air.
+		let mut cheated = legit;
+		let (mut x, mut speed) = (0.0, 2.0);
+
+		for tick in &mut cheated.ticks {
+			speed = if tick.flags & FL_ONGROUND == 0 { speed + 0.01 } else { 2.0 };
+			x += speed;
+			tick.origin[0] = x;
+		}
+
+		let suspicion = super::strafe_sync(&cheated).unwrap();
+		assert_eq!(suspicion.ban_type, BanType::StrafeHack);
+		assert_eq!(suspicion.score, 1.0);
+	}
+
+	#[test]
+	fn identical_turns() {
+		let cheated = bhop_replay(4, 48, 4, macro_turn, human_scroll);
+		let suspicion = super::identical_turns(&cheated).unwrap();
+
+		assert_eq!(suspicion.ban_type, BanType::StrafeMacro);
+		assert!(suspicion.score > 0.0);
+
+		let legit = bhop_replay(4, 48, 4, human_turn, human_scroll);
+		assert_eq!(super::identical_turns(&legit), None);
+	}
+
+	#[test]
+	fn identical_turns_with_rounding() {
+		let mut cheated = bhop_replay(1, 24, 4, |_| 0.0, human_scroll);
+
+		// Every angle is rounded to `f32` on its own, so the deltas between them differ slightly.
+		for (index, tick) in cheated.ticks.iter_mut().enumerate() {
+			tick.angles[1] = (100.0 + index as f64 * 1.1) as f32;
+		}
+
+		let suspicion = super::identical_turns(&cheated).unwrap();
+
+		assert_eq!(suspicion.ban_type, BanType::StrafeMacro);
+	}
+
+	#[test]
+	fn identical_turns_across_180() {
+		let mut cheated = bhop_replay(1, 24, 4, |_| 1.5, human_scroll);
+
+		// Start turning at 162° so the yaw wraps around to -180° halfway through the jump.
+		for tick in &mut cheated.ticks {
+			tick.angles[1] += 162.0;
+
+			if tick.angles[1] > 180.0 {
+				tick.angles[1] -= 360.0;
+			}
+		}
+
+		let suspicion = super::identical_turns(&cheated).unwrap();
+		assert_eq!(suspicion.ban_type, BanType::StrafeMacro);
+	}
+
+	#[test]
+	fn analyze() {
+		let legit = bhop_replay(4, 48, 4, human_turn, human_scroll);
+		assert_eq!(super::analyze(&legit), []);
+
+		let cheated = bhop_replay(24, 48, 0, macro_turn, human_scroll);
+		let ban_types = super::analyze(&cheated)
+			.into_iter()
+			.map(|suspicion| suspicion.ban_type)
+			.collect::<Vec<_>>();
+
+		assert!(ban_types.contains(&BanType::BhopHack));
+		assert!(ban_types.contains(&BanType::StrafeMacro));
+	}
+}
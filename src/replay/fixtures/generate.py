@@ -0,0 +1,167 @@
+#!/usr/bin/env python3
+"""Generates the synthetic replay fixtures in this directory.
+
+The layout follows the replay writer of the `gokz-replays` plugin. The values are
+made up, so these files only check that the parser and writer agree with each other and with
+that layout. See `README.md`.
+
+Usage: python3 generate.py [output directory]
+"""
+
+import os
+import struct
+import sys
+
+OUT = sys.argv[1] if len(sys.argv) > 1 else os.path.dirname(os.path.abspath(__file__))
+
+
+def string(x):
+	b = x.encode()
+	return bytes([len(b)]) + b
+
+
+def u32(x):
+	return struct.pack("<I", x & 0xFFFFFFFF)
+
+
+def f32(x):
+	return struct.pack("<f", x)
+
+
+def f32_bits(x):
+	return struct.unpack("<I", f32(x))[0]
+
+
+def u8(x):
+	return bytes([x])
+
+
+MAGIC = u32(0x676F6B7A)
+STEAM_ID32 = 322356345
+
+
+def v1_run():
+	header = (
+		MAGIC
+		+ u8(1)
+		+ string("1.3.0")
+		+ string("kz_lionharder")
+		+ u32(0)  # course
+		+ u32(1)  # mode
+		+ u32(0)  # style
+		+ f32(1424.766)
+		+ u32(0)  # teleports
+		+ u32(STEAM_ID32)
+		+ string("STEAM_1:1:161178172")
+		+ string("127.0.0.1")
+		+ string("AlphaKeks")
+	)
+
+	n = 64
+	ticks = u32(n)
+	for i in range(n):
+		ticks += (
+			f32(-1024 + i * 2.5)
+			+ f32(512.0)
+			+ f32(64.03125 + (i % 8))
+			+ f32(12.5)
+			+ f32(-90 + i * 0.75)
+			+ u32(2 if i % 4 == 0 else 0)  # buttons
+			+ u32(1 if i % 8 < 4 else 0)  # flags
+		)
+
+	return header + ticks
+
+
+def v2_ticks(n, seed):
+	out = b""
+	previous = [0] * 20
+
+	for i in range(n):
+		cells = [0] * 20
+		cells[1] = 2 if i % 5 == 0 else 0  # buttons
+		cells[2] = f32_bits(450.0 if i % 3 else 0.0)  # movement
+		cells[3] = f32_bits(-450.0 if i % 2 else 450.0)
+		cells[4] = 0
+		cells[5] = (i * 7 - 20) & 0xFFFFFFFF  # mouse
+		cells[6] = (-(i % 3)) & 0xFFFFFFFF
+		cells[7] = f32_bits(-1024 + i * 2.5 + seed)  # origin
+		cells[8] = f32_bits(512.0)
+		cells[9] = f32_bits(64.03125 + (i // 10))
+		cells[10] = f32_bits(12.5)  # angles
+		cells[11] = f32_bits(-90 + i * 0.75)
+		cells[12] = 0
+		cells[13] = f32_bits(250.0 + i)  # velocity
+		cells[14] = f32_bits(0.0)
+		cells[15] = f32_bits(-6.25 * (i % 4))
+		cells[16] = 1 if i % 8 < 4 else 0  # flags
+		cells[17] = f32_bits(128.0)  # packets per second
+		cells[18] = f32_bits(1.0)  # lagged movement value
+		cells[19] = 0  # buttons forced
+
+		stored = 0
+		for j in range(1, 20):
+			if cells[j] != previous[j]:
+				stored |= 1 << j
+
+		# GOKZ may store values that did not change; the writer has to preserve that.
+		if i % 16 == 0:
+			stored |= 1 << 17
+
+		out += u32(stored)
+		for j in range(1, 20):
+			if stored & (1 << j):
+				out += u32(cells[j])
+
+		previous = cells
+
+	return out
+
+
+def v2(kind, extra, n, seed):
+	header = (
+		MAGIC
+		+ u8(2)
+		+ u8(kind)
+		+ string("3.4.2")
+		+ string("kz_lionharder")
+		+ u32(48123904)  # map file size
+		+ u32(0x7F000001)  # server ip
+		+ u32(1697539200)  # timestamp
+		+ string("AlphaKeks")
+		+ u32(STEAM_ID32)
+		+ u8(1)  # mode
+		+ u8(0)  # style
+		+ f32(1.25)  # sensitivity
+		+ f32(0.022)  # m_yaw
+		+ f32(128.0)  # tickrate
+		+ u32(n)
+		+ u32(61)  # weapon
+		+ u32(42)  # knife
+	)
+
+	return header + extra + v2_ticks(n, seed)
+
+
+FIXTURES = {
+	"v1_run.replay": v1_run(),
+	"v2_run.replay": v2(0, f32(1424.766) + u8(0) + u32(0), 96, 0),
+	"v2_cheater.replay": v2(1, u8(3), 32, 7),
+	"v2_jump.replay": v2(
+		2,
+		u8(0)  # jump type
+		+ f32(286.4375)  # distance
+		+ u32(284)  # block distance
+		+ u8(7)  # strafe count
+		+ f32(87.5)  # sync
+		+ f32(276.8)  # pre
+		+ f32(312.2)  # max
+		+ u32(94),  # airtime
+		48,
+		3,
+	),
+}
+
+for name, data in FIXTURES.items():
+	with open(os.path.join(OUT, name), "wb") as file:
+		file.write(data)
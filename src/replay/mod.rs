@@ -0,0 +1,641 @@
+//! This module contains a reader and writer for GOKZ `.replay` files.
+//!
+//! A replay consists of a header describing the run followed by the player's movement for every
+//! tick. All numbers are stored in little endian and strings are prefixed with their length as a
+//! single byte. GOKZ has shipped two versions of the format:
+//!
+//! - Version 1 only stores runs. Every tick contains the player's origin, view angles, buttons and
+//!   entity flags.
+//! - Version 2 also stores cheater and jumpstat replays and records a lot more data per tick. To
+//!   keep files small, every tick starts with a bit field of which values changed since the
+//!   previous tick, and only those values are stored.
+//!
+//! [`Replay::parse`] followed by [`Replay::to_bytes`] produces the exact same bytes, so replays
+//! can be trimmed, anonymised or synthesized without corrupting them. See [`analysis`] for jump
+//! statistics computed from the tick data and [`anticheat`] for spotting cheated runs.
+//!
+//! Official implementation: <https://github.com/KZGlobalTeam/gokz/tree/master/addons/sourcemod/scripting/gokz-replays>
+
+use {
+	crate::{yeet, Mode, SteamID},
+	std::{io::Write, path::Path},
+};
+
+pub mod analysis;
+
+#[cfg(feature = "global-api")]
+pub mod anticheat;
+
+#[cfg(test)]
+mod tests;
+
+/// Every replay file starts with these 4 bytes (`"gokz"` as a little endian integer).
+pub const MAGIC_NUMBER: u32 = 0x676F_6B7A;
+
+/// A parsed GOKZ replay.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+	/// The version of GOKZ the replay was recorded with.
+	pub gokz_version: String,
+
+	/// The name of the map the replay was recorded on.
+	pub map_name: String,
+
+	/// The mode the player was using.
+	pub mode: Mode,
+
+	/// The GOKZ style the player was using; `0` is the normal style.
+	pub style: u8,
+
+	/// The player who was recorded.
+	pub steam_id: SteamID,
+
+	/// The name of the player at the time of recording.
+	pub player_alias: String,
+
+	/// What the replay shows.
+	pub kind: ReplayKind,
+
+	/// Data only present in one version of the format.
+	pub format: Format,
+
+	/// The recorded data for every tick.
+	pub ticks: Vec<TickData>,
+}
+
+/// What a [`Replay`] shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayKind {
+	/// A completed run.
+	Run {
+		/// The course (stage) of the run; `0` is the main course.
+		course: u8,
+
+		/// The time of the run in seconds.
+		time: f64,
+
+		/// The amount of teleports used during the run.
+		teleports: u32,
+	},
+
+	/// A player caught by the anticheat. Only supported by version 2.
+	Cheater {
+		/// The reason the player was flagged for.
+		reason: u8,
+	},
+
+	/// A jumpstat. Only supported by version 2.
+	#[allow(missing_docs)]
+	Jump {
+		jump_type: u8,
+		distance: f32,
+		block_distance: u32,
+		strafe_count: u8,
+		sync: f32,
+		pre: f32,
+		max: f32,
+		airtime: u32,
+	},
+}
+
+/// Header data only present in one version of the format.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Format {
+	/// Version 1.
+	V1 {
+		/// The player's SteamID in the `STEAM_1:1:161178172` format.
+		steam_id2: String,
+
+		/// The player's IP address.
+		ip_address: String,
+	},
+
+	/// Version 2.
+	#[allow(missing_docs)]
+	V2 {
+		map_file_size: u32,
+
+		/// The IPv4 address of the server.
+		server_ip: u32,
+
+		/// Unix timestamp of when the replay was recorded.
+		timestamp: u32,
+
+		sensitivity: f32,
+		m_yaw: f32,
+		tickrate: f32,
+		weapon: u32,
+		knife: u32,
+	},
+}
+
+impl Format {
+	/// The version number stored in the file.
+	pub const fn version(&self) -> u8 {
+		match self {
+			Format::V1 { .. } => 1,
+			Format::V2 { .. } => 2,
+		}
+	}
+}
+
+/// The recorded data for a single tick of a [`Replay`].
+///
+/// Version 1 replays only store `origin`, the first two `angles`, `buttons` and `flags`; all
+/// other values are `0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickData {
+	/// The player's position (x, y, z).
+	pub origin: [f32; 3],
+
+	/// The player's view angles (pitch, yaw, roll).
+	pub angles: [f32; 3],
+
+	/// The buttons the player was pressing (`IN_*` flags).
+	pub buttons: u32,
+
+	/// The player's entity flags (`FL_*` flags).
+	pub flags: u32,
+
+	/// The player's movement input (forward, side, up).
+	pub movement: [f32; 3],
+
+	/// The player's mouse movement (x, y).
+	pub mouse: [i32; 2],
+
+	/// The player's velocity (x, y, z).
+	pub velocity: [f32; 3],
+
+	/// How many packets per second the player was sending.
+	pub packets_per_second: f32,
+
+	/// The player's `m_flLaggedMovementValue`.
+	pub lagged_movement_value: f32,
+
+	/// Buttons forced by the server.
+	pub buttons_forced: u32,
+
+	/// Version 2 only: which values were stored for this tick.
+	///
+	/// Bits `1..20` stand for `buttons`, `movement` (3), `mouse` (2), `origin` (3), `angles` (3),
+	/// `velocity` (3), `flags`, `packets_per_second`, `lagged_movement_value` and
+	/// `buttons_forced`, in that order.
+	///
+	/// Values that differ from the previous tick (or from `0` for the first tick) are always
+	/// stored, so this only has to be set to store unchanged values as well.
+	pub delta_flags: u32,
+}
+
+/// The amount of 4 byte cells in a version 2 tick, including the delta flags.
+const V2_TICK_CELLS: usize = 20;
+
+impl TickData {
+	/// The values stored in a version 2 tick, in order. The first cell holds the delta flags.
+	fn to_cells(self) -> [u32; V2_TICK_CELLS] {
+		let f = f32::to_bits;
+		let [mx, my] = self.mouse.map(|mouse| mouse as u32);
+		let ([vx, vy, vz], [ox, oy, oz]) = (self.movement.map(f), self.origin.map(f));
+		let ([pitch, yaw, roll], [x, y, z]) = (self.angles.map(f), self.velocity.map(f));
+
+		[
+			self.delta_flags,
+			self.buttons,
+			vx,
+			vy,
+			vz,
+			mx,
+			my,
+			ox,
+			oy,
+			oz,
+			pitch,
+			yaw,
+			roll,
+			x,
+			y,
+			z,
+			self.flags,
+			f(self.packets_per_second),
+			f(self.lagged_movement_value),
+			self.buttons_forced,
+		]
+	}
+
+	fn from_cells(cells: [u32; V2_TICK_CELLS]) -> Self {
+		let f = f32::from_bits;
+
+		Self {
+			delta_flags: cells[0],
+			buttons: cells[1],
+			movement: [f(cells[2]), f(cells[3]), f(cells[4])],
+			mouse: [cells[5] as i32, cells[6] as i32],
+			origin: [f(cells[7]), f(cells[8]), f(cells[9])],
+			angles: [f(cells[10]), f(cells[11]), f(cells[12])],
+			velocity: [f(cells[13]), f(cells[14]), f(cells[15])],
+			flags: cells[16],
+			packets_per_second: f(cells[17]),
+			lagged_movement_value: f(cells[18]),
+			buttons_forced: cells[19],
+		}
+	}
+}
+
+impl Replay {
+	/// Parses a replay from the contents of a `.replay` file.
+	pub fn parse(bytes: &[u8]) -> crate::Result<Self> {
+		let mut reader = Reader { bytes, position: 0 };
+
+		let magic_number = reader.u32()?;
+		if magic_number != MAGIC_NUMBER {
+			yeet!(InvalidReplay(format!("invalid magic number `{magic_number:#X}`")));
+		}
+
+		let replay = match reader.u8()? {
+			1 => Self::parse_v1(&mut reader)?,
+			2 => Self::parse_v2(&mut reader)?,
+			version => yeet!(InvalidReplay(format!("unsupported format version `{version}`"))),
+		};
+
+		if reader.position != bytes.len() {
+			yeet!(InvalidReplay(format!("unexpected data at byte {}", reader.position)));
+		}
+
+		Ok(replay)
+	}
+
+	fn parse_v1(reader: &mut Reader<'_>) -> crate::Result<Self> {
+		let gokz_version = reader.string()?;
+		let map_name = reader.string()?;
+		let course = reader.u32()?;
+		let mode = mode_from_gokz(reader.u32()?)?;
+		let style = reader.u32()?;
+		let time = f64::from(reader.f32()?);
+		let teleports = reader.u32()?;
+		let steam_id = SteamID::try_from(reader.u32()?)?;
+		let steam_id2 = reader.string()?;
+		let ip_address = reader.string()?;
+		let player_alias = reader.string()?;
+
+		let (Ok(course), Ok(style)) = (u8::try_from(course), u8::try_from(style)) else {
+			yeet!(InvalidReplay(format!("invalid course `{course}` or style `{style}`")));
+		};
+
+		let tick_count = reader.u32()?;
+		let ticks = (0..tick_count)
+			.map(|_| {
+				Ok(TickData {
+					origin: [reader.f32()?, reader.f32()?, reader.f32()?],
+					angles: [reader.f32()?, reader.f32()?, 0.0],
+					buttons: reader.u32()?,
+					flags: reader.u32()?,
+					..Default::default()
+				})
+			})
+			.collect::<crate::Result<Vec<_>>>()?;
+
+		Ok(Self {
+			gokz_version,
+			map_name,
+			mode,
+			style,
+			steam_id,
+			player_alias,
+			kind: ReplayKind::Run { course, time, teleports },
+			format: Format::V1 { steam_id2, ip_address },
+			ticks,
+		})
+	}
+
+	fn parse_v2(reader: &mut Reader<'_>) -> crate::Result<Self> {
+		let replay_type = reader.u8()?;
+		let gokz_version = reader.string()?;
+		let map_name = reader.string()?;
+		let map_file_size = reader.u32()?;
+		let server_ip = reader.u32()?;
+		let timestamp = reader.u32()?;
+		let player_alias = reader.string()?;
+		let steam_id = SteamID::try_from(reader.u32()?)?;
+		let mode = mode_from_gokz(u32::from(reader.u8()?))?;
+		let style = reader.u8()?;
+		let sensitivity = reader.f32()?;
+		let m_yaw = reader.f32()?;
+		let tickrate = reader.f32()?;
+		let tick_count = reader.u32()?;
+		let weapon = reader.u32()?;
+		let knife = reader.u32()?;
+
+		let kind = match replay_type {
+			0 => ReplayKind::Run {
+				time: f64::from(reader.f32()?),
+				course: reader.u8()?,
+				teleports: reader.u32()?,
+			},
+			1 => ReplayKind::Cheater { reason: reader.u8()? },
+			2 => ReplayKind::Jump {
+				jump_type: reader.u8()?,
+				distance: reader.f32()?,
+				block_distance: reader.u32()?,
+				strafe_count: reader.u8()?,
+				sync: reader.f32()?,
+				pre: reader.f32()?,
+				max: reader.f32()?,
+				airtime: reader.u32()?,
+			},
+			replay_type => yeet!(InvalidReplay(format!("invalid replay type `{replay_type}`"))),
+		};
+
+		let mut cells = [0; V2_TICK_CELLS];
+		let ticks = (0..tick_count)
+			.map(|_| {
+				let delta_flags = reader.u32()?;
+				cells[0] = delta_flags;
+
+				for (index, cell) in cells.iter_mut().enumerate().skip(1) {
+					if cells_changed(delta_flags, index) {
+						*cell = reader.u32()?;
+					}
+				}
+
+				Ok(TickData::from_cells(cells))
+			})
+			.collect::<crate::Result<Vec<_>>>()?;
+
+		Ok(Self {
+			gokz_version,
+			map_name,
+			mode,
+			style,
+			steam_id,
+			player_alias,
+			kind,
+			format: Format::V2 {
+				map_file_size,
+				server_ip,
+				timestamp,
+				sensitivity,
+				m_yaw,
+				tickrate,
+				weapon,
+				knife,
+			},
+			ticks,
+		})
+	}
+
+	/// Reads and parses the `.replay` file at the given `path`.
+	pub fn from_file(path: impl AsRef<Path>) -> crate::Result<Self> {
+		let path = path.as_ref();
+		let bytes = std::fs::read(path).map_err(|err| {
+			crate::Error::Custom(format!("Failed to read `{}`: {err}", path.display()))
+		})?;
+
+		Self::parse(&bytes)
+	}
+
+	/// Encodes this replay in the `.replay` format.
+	pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+		let mut bytes = Vec::with_capacity(256 + self.ticks.len() * V2_TICK_CELLS * 4);
+		self.write_to(&mut bytes)?;
+		Ok(bytes)
+	}
+
+	/// Encodes this replay in the `.replay` format and writes it to `writer`.
+	///
+	/// This fails if the replay cannot be represented in its [`Format`], e.g. a jumpstat in
+	/// version 1 or strings longer than 255 bytes.
+	pub fn write_to(&self, mut writer: impl Write) -> crate::Result<()> {
+		let mut w = Writer(Vec::new());
+
+		w.u32(MAGIC_NUMBER);
+		w.u8(self.format.version());
+
+		match &self.format {
+			Format::V1 { steam_id2, ip_address } => {
+				let ReplayKind::Run { course, time, teleports } = self.kind else {
+					yeet!(InvalidReplay("version 1 only supports runs"));
+				};
+
+				w.string(&self.gokz_version)?;
+				w.string(&self.map_name)?;
+				w.u32(u32::from(course));
+				w.u32(mode_to_gokz(self.mode));
+				w.u32(u32::from(self.style));
+				w.f32(time as f32);
+				w.u32(teleports);
+				w.u32(self.steam_id.community_id());
+				w.string(steam_id2)?;
+				w.string(ip_address)?;
+				w.string(&self.player_alias)?;
+				w.u32(self.ticks.len() as u32);
+
+				for tick in &self.ticks {
+					for value in tick.origin.iter().chain(&tick.angles[..2]) {
+						w.f32(*value);
+					}
+
+					w.u32(tick.buttons);
+					w.u32(tick.flags);
+				}
+			}
+
+			&Format::V2 {
+				map_file_size,
+				server_ip,
+				timestamp,
+				sensitivity,
+				m_yaw,
+				tickrate,
+				weapon,
+				knife,
+			} => {
+				w.u8(match self.kind {
+					ReplayKind::Run { .. } => 0,
+					ReplayKind::Cheater { .. } => 1,
+					ReplayKind::Jump { .. } => 2,
+				});
+				w.string(&self.gokz_version)?;
+				w.string(&self.map_name)?;
+				w.u32(map_file_size);
+				w.u32(server_ip);
+				w.u32(timestamp);
+				w.string(&self.player_alias)?;
+				w.u32(self.steam_id.community_id());
+				w.u8(mode_to_gokz(self.mode) as u8);
+				w.u8(self.style);
+				w.f32(sensitivity);
+				w.f32(m_yaw);
+				w.f32(tickrate);
+				w.u32(self.ticks.len() as u32);
+				w.u32(weapon);
+				w.u32(knife);
+
+				match self.kind {
+					ReplayKind::Run { course, time, teleports } => {
+						w.f32(time as f32);
+						w.u8(course);
+						w.u32(teleports);
+					}
+					ReplayKind::Cheater { reason } => w.u8(reason),
+					ReplayKind::Jump {
+						jump_type,
+						distance,
+						block_distance,
+						strafe_count,
+						sync,
+						pre,
+						max,
+						airtime,
+					} => {
+						w.u8(jump_type);
+						w.f32(distance);
+						w.u32(block_distance);
+						w.u8(strafe_count);
+						w.f32(sync);
+						w.f32(pre);
+						w.f32(max);
+						w.u32(airtime);
+					}
+				}
+
+				// Like when parsing, the first tick is compared against all zeroes.
+				let mut previous = [0; V2_TICK_CELLS];
+
+				for tick in &self.ticks {
+					let mut cells = tick.to_cells();
+
+					for index in 1..V2_TICK_CELLS {
+						if previous[index] != cells[index] {
+							cells[0] |= 1 << index;
+						}
+					}
+
+					w.u32(cells[0]);
+
+					for (index, &cell) in cells.iter().enumerate().skip(1) {
+						if cells_changed(cells[0], index) {
+							w.u32(cell);
+						}
+					}
+
+					previous = cells;
+				}
+			}
+		}
+
+		writer
+			.write_all(&w.0)
+			.map_err(|err| crate::Error::InvalidReplay(err.to_string()))
+	}
+}
+
+impl TryFrom<&[u8]> for Replay {
+	type Error = crate::Error;
+
+	fn try_from(bytes: &[u8]) -> crate::Result<Self> {
+		Self::parse(bytes)
+	}
+}
+
+/// Whether the cell at `index` is stored according to the given `delta_flags`.
+const fn cells_changed(delta_flags: u32, index: usize) -> bool {
+	delta_flags & (1 << index) != 0
+}
+
+/// GOKZ numbers its modes starting at 0, in a different order than [`Mode`].
+fn mode_from_gokz(mode: u32) -> crate::Result<Mode> {
+	Ok(match mode {
+		0 => Mode::Vanilla,
+		1 => Mode::SimpleKZ,
+		2 => Mode::KZTimer,
+		mode => yeet!(InvalidReplay(format!("invalid mode `{mode}`"))),
+	})
+}
+
+const fn mode_to_gokz(mode: Mode) -> u32 {
+	match mode {
+		Mode::Vanilla => 0,
+		Mode::SimpleKZ => 1,
+		Mode::KZTimer => 2,
+	}
+}
+
+/// Reads little endian values from a byte slice.
+struct Reader<'a> {
+	bytes: &'a [u8],
+	position: usize,
+}
+
+impl Reader<'_> {
+	fn slice(&mut self, len: usize) -> crate::Result<&[u8]> {
+		let Some(bytes) = self
+			.bytes
+			.get(self.position..self.position + len)
+		else {
+			yeet!(InvalidReplay(format!("unexpected end of file at byte {}", self.position)));
+		};
+
+		self.position += len;
+
+		Ok(bytes)
+	}
+
+	fn take<const N: usize>(&mut self) -> crate::Result<[u8; N]> {
+		self.slice(N).map(|bytes| {
+			bytes
+				.try_into()
+				.expect("The slice has exactly `N` bytes.")
+		})
+	}
+
+	fn u8(&mut self) -> crate::Result<u8> {
+		self.take::<1>().map(|[byte]| byte)
+	}
+
+	fn u32(&mut self) -> crate::Result<u32> {
+		self.take().map(u32::from_le_bytes)
+	}
+
+	fn f32(&mut self) -> crate::Result<f32> {
+		self.take().map(f32::from_le_bytes)
+	}
+
+	fn string(&mut self) -> crate::Result<String> {
+		let len = self.u8()? as usize;
+		let bytes = self.slice(len)?;
+
+		String::from_utf8(bytes.to_vec())
+			.map_err(|_| crate::Error::InvalidReplay(String::from("invalid UTF-8 in string")))
+	}
+}
+
+/// Writes little endian values into a buffer.
+struct Writer(Vec<u8>);
+
+impl Writer {
+	fn u8(&mut self, value: u8) {
+		self.0.push(value);
+	}
+
+	fn u32(&mut self, value: u32) {
+		self.0.extend_from_slice(&value.to_le_bytes());
+	}
+
+	fn f32(&mut self, value: f32) {
+		self.0.extend_from_slice(&value.to_le_bytes());
+	}
+
+	fn string(&mut self, value: &str) -> crate::Result<()> {
+		let Ok(len) = u8::try_from(value.len()) else {
+			yeet!(InvalidReplay(format!("`{value}` is longer than 255 bytes")));
+		};
+
+		self.u8(len);
+		self.0.extend_from_slice(value.as_bytes());
+
+		Ok(())
+	}
+}
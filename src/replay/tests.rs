@@ -0,0 +1,127 @@
+use {
+	super::{Format, Replay, ReplayKind, TickData},
+	crate::{Error, Mode, SteamID},
+	pretty_assertions::assert_eq,
+};
+
+// These fixtures are synthetic, see `fixtures/README.md`.
+const V1_RUN: &[u8] = include_bytes!("fixtures/v1_run.replay");
+const V2_RUN: &[u8] = include_bytes!("fixtures/v2_run.replay");
+const V2_CHEATER: &[u8] = include_bytes!("fixtures/v2_cheater.replay");
+const V2_JUMP: &[u8] = include_bytes!("fixtures/v2_jump.replay");
+
+/// A replay of a finished run consisting of the given `ticks`, used to test the analysis
+/// functions.
+pub(super) fn replay(ticks: Vec<TickData>) -> Replay {
+	Replay {
+		gokz_version: String::from("3.4.2"),
+		map_name: String::from("kz_lionharder"),
+		mode: Mode::KZTimer,
+		style: 0,
+		steam_id: SteamID::try_from(76561198282622073_u64).unwrap(),
+		player_alias: String::from("AlphaKeks"),
+		kind: ReplayKind::Run { course: 0, time: 60.0, teleports: 0 },
+		format: Format::V1 { steam_id2: String::new(), ip_address: String::new() },
+		ticks,
+	}
+}
+
+#[test]
+fn round_trip() {
+	for fixture in [V1_RUN, V2_RUN, V2_CHEATER, V2_JUMP] {
+		let replay = Replay::parse(fixture).unwrap();
+		let bytes = replay.to_bytes().unwrap();
+
+		assert_eq!(bytes, fixture);
+		assert_eq!(Replay::parse(&bytes).unwrap(), replay);
+	}
+}
+
+#[test]
+fn parse_v1() {
+	let replay = Replay::parse(V1_RUN).unwrap();
+
+	assert_eq!(replay.format.version(), 1);
+	assert_eq!(replay.map_name, "kz_lionharder");
+	assert_eq!(replay.mode, Mode::SimpleKZ);
+	assert_eq!(replay.steam_id, SteamID::try_from(76561198282622073_u64).unwrap());
+	assert_eq!(replay.player_alias, "AlphaKeks");
+	assert!(matches!(
+		replay.kind,
+		ReplayKind::Run { course: 0, teleports: 0, time } if (time - 1424.766).abs() < 0.001
+	));
+	assert_eq!(replay.ticks.len(), 64);
+	assert_eq!(replay.ticks[1], TickData {
+		origin: [-1021.5, 512.0, 65.03125],
+		angles: [12.5, -89.25, 0.0],
+		flags: 1,
+		..Default::default()
+	});
+}
+
+#[test]
+fn parse_v2() {
+	let replay = Replay::parse(V2_RUN).unwrap();
+
+	assert_eq!(replay.format.version(), 2);
+	assert!(matches!(replay.format, Format::V2 { weapon: 61, knife: 42, .. }));
+	assert_eq!(replay.ticks.len(), 96);
+
+	// Values that are not stored are carried over from the previous tick.
+	let (previous, tick) = (replay.ticks[10], replay.ticks[11]);
+	assert_eq!(tick.origin[1], 512.0);
+	assert_eq!(tick.packets_per_second, 128.0);
+	assert_eq!(tick.origin[1], previous.origin[1]);
+	assert_eq!(tick.origin[0], previous.origin[0] + 2.5);
+
+	let replay = Replay::parse(V2_CHEATER).unwrap();
+	assert_eq!(replay.kind, ReplayKind::Cheater { reason: 3 });
+
+	let replay = Replay::parse(V2_JUMP).unwrap();
+	assert!(matches!(replay.kind, ReplayKind::Jump { block_distance: 284, strafe_count: 7, .. }));
+}
+
+#[test]
+fn edit_and_write() {
+	let mut replay = Replay::parse(V2_RUN).unwrap();
+	replay.player_alias = String::from("anonymous");
+	replay.ticks.truncate(10);
+	replay.ticks[5].origin[2] = 1337.0;
+
+	// Changed values are stored even if their delta flag was not set.
+	let without_delta_flags = |replay: Replay| Replay {
+		ticks: replay
+			.ticks
+			.into_iter()
+			.map(|tick| TickData { delta_flags: 0, ..tick })
+			.collect(),
+		..replay
+	};
+
+	let edited = Replay::parse(&replay.to_bytes().unwrap()).unwrap();
+	assert_eq!(edited.ticks[5].origin[2], 1337.0);
+	assert_eq!(without_delta_flags(edited), without_delta_flags(replay));
+}
+
+#[test]
+fn invalid_replays() {
+	let mut wrong_magic = V1_RUN.to_vec();
+	wrong_magic[0] = 0;
+	assert!(matches!(Replay::parse(&wrong_magic), Err(Error::InvalidReplay(_))));
+
+	let mut wrong_version = V1_RUN.to_vec();
+	wrong_version[4] = 3;
+	assert!(matches!(Replay::parse(&wrong_version), Err(Error::InvalidReplay(_))));
+
+	let truncated = &V2_RUN[..V2_RUN.len() - 1];
+	assert!(matches!(Replay::parse(truncated), Err(Error::InvalidReplay(_))));
+
+	let mut trailing = V2_RUN.to_vec();
+	trailing.push(0);
+	assert!(matches!(Replay::parse(&trailing), Err(Error::InvalidReplay(_))));
+
+	let mut jump_in_v1 = Replay::parse(V2_JUMP).unwrap();
+	jump_in_v1.format =
+		Format::V1 { steam_id2: String::from("STEAM_1:1:161178172"), ip_address: String::new() };
+	assert!(matches!(jump_in_v1.to_bytes(), Err(Error::InvalidReplay(_))));
+}
@@ -223,7 +223,13 @@ impl SteamID {
 	/// Returns a link to fetch this player from the GlobalAPI.
 	#[inline]
 	pub fn api(&self) -> String {
-		format!("https://kztimerglobal.com/api/v2/players/steam_id/{}", self)
+		self.api_with_base_url("https://kztimerglobal.com/api/v2")
+	}
+
+	/// Same as [`Self::api`] but with a custom base URL.
+	#[inline]
+	pub fn api_with_base_url(&self, base_url: &str) -> String {
+		format!("{base_url}/players/steam_id/{}", self)
 	}
 
 	/// Returns a link to the player's KZ:GO profile.
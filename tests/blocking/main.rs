@@ -0,0 +1,72 @@
+#![cfg(all(feature = "blocking", feature = "mock"))]
+
+use {
+	color_eyre::Result,
+	gokz_rs::{blocking::GlobalApi, global_api, http::Pagination, Error},
+};
+
+fn global_api(mock_server: &global_api::mock::MockServer) -> Result<GlobalApi> {
+	Ok(GlobalApi::builder()
+		.base_url(mock_server.base_url())
+		.health_url(mock_server.health_url())
+		.build()?)
+}
+
+#[test]
+fn get_maps() -> Result<()> {
+	let mock_server = global_api::mock::MockServer::start()?;
+	let global_api = global_api(&mock_server)?;
+
+	let maps = global_api.get_maps(true)?;
+	assert!(!maps.is_empty());
+
+	let params = global_api::maps::Params { is_validated: Some(true), ..Default::default() };
+	let streamed = global_api
+		.iter_maps_with(&params, Pagination::new().page_size(2))
+		.collect::<gokz_rs::Result<Vec<_>>>()?;
+
+	assert_eq!(maps, streamed);
+	Ok(())
+}
+
+#[test]
+fn get_record() -> Result<()> {
+	let mock_server = global_api::mock::MockServer::start()?;
+	let global_api = global_api(&mock_server)?;
+
+	let record = global_api.get_record(14202658)?;
+	assert_eq!(record.player_name, "AlphaKeks");
+
+	assert!(global_api.get_record(1).is_err_and(|err| err.is_not_found()));
+	Ok(())
+}
+
+#[cfg(feature = "kzgo-api")]
+#[test]
+fn kzgo_get_map() -> Result<()> {
+	let mock_server = gokz_rs::kzgo_api::mock::MockServer::start()?;
+	let kzgo_api = gokz_rs::blocking::KzgoApi::builder()
+		.base_url(mock_server.base_url())
+		.build()?;
+
+	let map = kzgo_api.get_map("kz_lionharder")?;
+	assert_eq!(map.id, 992);
+	Ok(())
+}
+
+#[test]
+fn inside_runtime() -> Result<()> {
+	let mock_server = global_api::mock::MockServer::start()?;
+	let global_api = global_api(&mock_server)?;
+	let runtime = tokio::runtime::Runtime::new()?;
+
+	runtime.block_on(async {
+		assert_eq!(global_api.get_maps(true), Err(Error::BlockingInAsyncContext));
+
+		let mut iter = global_api.iter_maps_with(&Default::default(), Pagination::new());
+		assert_eq!(iter.next(), Some(Err(Error::BlockingInAsyncContext)));
+		assert_eq!(iter.next(), None);
+	});
+
+	Ok(())
+}
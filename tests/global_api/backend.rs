@@ -0,0 +1,121 @@
+use {
+	color_eyre::Result,
+	gokz_rs::{
+		backend::{Fallback, Health, KzBackend, Map, Player, Record},
+		global_api::GlobalApi,
+		http::RetryPolicy,
+		Error, MapIdentifier, Mode, PlayerIdentifier, Runtype, SteamID,
+	},
+};
+
+/// A backend which does not support anything, like KZ:GO for most operations.
+pub struct Unsupported;
+
+impl KzBackend for Unsupported {
+	async fn get_map(&self, _: MapIdentifier) -> gokz_rs::Result<Map> {
+		Err(Error::Unsupported(String::from("`get_map`")))
+	}
+
+	async fn get_maps(&self) -> gokz_rs::Result<Vec<Map>> {
+		Err(Error::Unsupported(String::from("`get_maps`")))
+	}
+
+	async fn get_wr(&self, _: MapIdentifier, _: u8, _: Mode, _: Runtype) -> gokz_rs::Result<Record> {
+		Err(Error::Unsupported(String::from("`get_wr`")))
+	}
+
+	async fn get_pb(
+		&self,
+		_: PlayerIdentifier,
+		_: MapIdentifier,
+		_: u8,
+		_: Mode,
+		_: Runtype,
+	) -> gokz_rs::Result<Record> {
+		Err(Error::Unsupported(String::from("`get_pb`")))
+	}
+
+	async fn get_maptop(
+		&self,
+		_: MapIdentifier,
+		_: u8,
+		_: Mode,
+		_: Runtype,
+		_: usize,
+	) -> gokz_rs::Result<Vec<Record>> {
+		Err(Error::Unsupported(String::from("`get_maptop`")))
+	}
+
+	async fn get_player(&self, _: PlayerIdentifier) -> gokz_rs::Result<Player> {
+		Err(Error::Unsupported(String::from("`get_player`")))
+	}
+
+	async fn healthcheck(&self) -> gokz_rs::Result<Health> {
+		Err(Error::Unsupported(String::from("`healthcheck`")))
+	}
+}
+
+/// A GlobalAPI client that can't reach any server.
+pub fn unavailable() -> GlobalApi {
+	GlobalApi::builder()
+		.base_url("http://127.0.0.1:1")
+		.health_url("http://127.0.0.1:1")
+		.retry(RetryPolicy::none())
+		.build()
+		.expect("This is a valid URL.")
+}
+
+async fn lionharder_wr(backend: impl KzBackend) -> Result<()> {
+	let map = backend.get_map("kz_lionharder".into()).await?;
+	assert_eq!(map.id, 992);
+
+	let wr = backend
+		.get_wr(map.id.into(), 0, Mode::SimpleKZ, Runtype::Pro)
+		.await?;
+	assert_eq!(wr.map_id, map.id);
+	assert_eq!(wr.runtype(), Runtype::Pro);
+
+	let maptop = backend
+		.get_maptop(map.name.into(), 0, Mode::SimpleKZ, Runtype::Pro, 10)
+		.await?;
+	assert_eq!(maptop[0], wr);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn backend() -> Result<()> {
+	lionharder_wr(&*crate::GLOBAL_API).await?;
+
+	let steam_id = SteamID::try_from(76561198282622073_u64)?;
+	let player = KzBackend::get_player(&*crate::GLOBAL_API, steam_id.into()).await?;
+	assert_eq!(player.name, "AlphaKeks");
+	assert_eq!(player.is_banned, Some(false));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn fallback() -> Result<()> {
+	lionharder_wr(Fallback::new(unavailable(), &*crate::GLOBAL_API)).await
+}
+
+#[tokio::test]
+async fn fallback_errors() -> Result<()> {
+	// A missing course is not a reason to ask another API.
+	let error = Fallback::new(&*crate::GLOBAL_API, Unsupported)
+		.get_wr("kz_lionharder".into(), 9, Mode::SimpleKZ, Runtype::Pro)
+		.await
+		.unwrap_err();
+
+	assert!(error.is_not_found(), "{error:?}");
+
+	// The secondary not supporting an operation does not hide why the primary failed.
+	let error = Fallback::new(unavailable(), Unsupported)
+		.get_wr("kz_lionharder".into(), 0, Mode::SimpleKZ, Runtype::Pro)
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, Error::Connection { .. }), "{error:?}");
+	Ok(())
+}
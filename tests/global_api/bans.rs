@@ -8,7 +8,47 @@ async fn get_bans() -> Result<()> {
 	let alphakeks = SteamID::try_from(76561198282622073_u64)?;
 	let params = global_api::bans::Params { steam_id: Some(alphakeks), ..Default::default() };
 
-	let bans = global_api::get_bans_with(&params, &crate::GOKZ_CLIENT).await?;
+	let bans = crate::GLOBAL_API.get_bans_with(&params).await?;
 	assert_eq!(bans.len(), 1);
 	Ok(())
 }
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn create_ban() -> Result<()> {
+	use gokz_rs::{
+		global_api::{
+			bans::{BanType, NewBan},
+			mock::MockServer,
+			GlobalApi,
+		},
+		Error,
+	};
+
+	let mock_server = MockServer::start()?;
+	let new_ban = NewBan {
+		steam_id: SteamID::try_from(76561198282622073_u64)?,
+		ban_type: BanType::BhopHack,
+		notes: String::from("perfs"),
+		stats: String::new(),
+		ip: None,
+	};
+
+	let error = mock_server
+		.client()
+		.create_ban(&new_ban)
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, Error::Unauthorized { .. }));
+
+	let global_api = GlobalApi::builder()
+		.base_url(mock_server.base_url())
+		.api_key("secret")
+		.build()?;
+
+	let ban = global_api.create_ban(&new_ban).await?;
+	assert_eq!(ban.steam_id, new_ban.steam_id);
+	assert_eq!(ban.notes, "perfs");
+	Ok(())
+}
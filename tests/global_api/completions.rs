@@ -0,0 +1,82 @@
+use {
+	color_eyre::Result,
+	gokz_rs::{
+		global_api::{
+			completions::{completion, Completion},
+			filters, records, GlobalApi,
+		},
+		Mode, Runtype, SteamID, Tier,
+	},
+};
+
+async fn alphakeks_skz(global_api: &GlobalApi) -> Result<Completion> {
+	let steam_id = SteamID::try_from(76561198282622073_u64)?;
+	let mut records = Vec::new();
+
+	for runtype in [Runtype::Pro, Runtype::TP] {
+		let params = records::Params {
+			steam_id: Some(steam_id),
+			mode: Some(Mode::SimpleKZ),
+			stage: Some(0),
+			runtype: Some(runtype),
+			limit: Some(9999),
+			..Default::default()
+		};
+
+		records.extend(global_api.get_records_with(&params).await?);
+	}
+
+	let maps = global_api.get_maps(true).await?;
+	let filters = global_api
+		.get_filters_with(&filters::Params {
+			modes: Some(vec![Mode::SimpleKZ]),
+			stages: Some(vec![0]),
+			tickrate: Some(128),
+			limit: Some(9999),
+			..Default::default()
+		})
+		.await?;
+
+	Ok(completion(Mode::SimpleKZ, &records, &maps, &filters))
+}
+
+#[tokio::test]
+async fn get_completion() -> Result<()> {
+	let completion = alphakeks_skz(&crate::GLOBAL_API).await?;
+
+	for runtype in [Runtype::Pro, Runtype::TP] {
+		let completed = completion.completed.runtype(runtype);
+		let possible = completion.possible.runtype(runtype);
+
+		assert!(completed.total > 0);
+		assert!(completed.total <= possible.total);
+
+		for tier in (1..=7).map(|tier| Tier::try_from(tier).unwrap()) {
+			assert!(completed.tier(tier) <= possible.tier(tier));
+			assert!((0.0..=100.0).contains(&completion.percentage(runtype, Some(tier))));
+		}
+
+		assert!((0.0..=100.0).contains(&completion.percentage(runtype, None)));
+	}
+
+	// PRO runs also count as TP completions.
+	assert!(completion.completed.tp.total >= completion.completed.pro.total);
+
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn get_completion_mock() -> Result<()> {
+	let mock_server = gokz_rs::global_api::mock::MockServer::start()?;
+	let completion = alphakeks_skz(&mock_server.client()).await?;
+
+	assert_eq!(completion.possible.pro.total, 3);
+	assert_eq!(completion.completed.pro.total, 2);
+	assert_eq!(completion.completed.pro.tier(Tier::Death), 1);
+	assert_eq!(completion.completed.tp.total, 2);
+	assert_eq!(completion.percentage(Runtype::TP, Some(Tier::Death)), 100.0);
+	assert_eq!(completion.percentage(Runtype::Pro, Some(Tier::VeryEasy)), 0.0);
+
+	Ok(())
+}
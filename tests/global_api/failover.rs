@@ -0,0 +1,163 @@
+#![cfg(feature = "mock")]
+
+use {
+	color_eyre::Result,
+	gokz_rs::{
+		backend::{self, Failover, KzBackend, Map, Player, Record},
+		global_api::{
+			mock::{Fixtures, MockServer},
+			GlobalApi, Health,
+		},
+		http::RetryPolicy,
+		Error, MapIdentifier, Mode, PlayerIdentifier, Runtype,
+	},
+};
+
+/// Either a GlobalAPI client or a backend which does not support anything.
+enum TestBackend {
+	GlobalApi(GlobalApi),
+	Unsupported,
+}
+
+macro_rules! dispatch {
+	($self:ident.$method:ident($($arg:expr),*)) => {
+		match $self {
+			Self::GlobalApi(global_api) => KzBackend::$method(global_api, $($arg),*).await,
+			Self::Unsupported => KzBackend::$method(&crate::backend::Unsupported, $($arg),*).await,
+		}
+	};
+}
+
+impl KzBackend for TestBackend {
+	async fn get_map(&self, map: MapIdentifier) -> gokz_rs::Result<Map> {
+		dispatch!(self.get_map(map))
+	}
+
+	async fn get_maps(&self) -> gokz_rs::Result<Vec<Map>> {
+		dispatch!(self.get_maps())
+	}
+
+	async fn get_wr(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> gokz_rs::Result<Record> {
+		dispatch!(self.get_wr(map, course, mode, runtype))
+	}
+
+	async fn get_pb(
+		&self,
+		player: PlayerIdentifier,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+	) -> gokz_rs::Result<Record> {
+		dispatch!(self.get_pb(player, map, course, mode, runtype))
+	}
+
+	async fn get_maptop(
+		&self,
+		map: MapIdentifier,
+		course: u8,
+		mode: Mode,
+		runtype: Runtype,
+		n: usize,
+	) -> gokz_rs::Result<Vec<Record>> {
+		dispatch!(self.get_maptop(map, course, mode, runtype, n))
+	}
+
+	async fn get_player(&self, player: PlayerIdentifier) -> gokz_rs::Result<Player> {
+		dispatch!(self.get_player(player))
+	}
+
+	async fn healthcheck(&self) -> gokz_rs::Result<backend::Health> {
+		dispatch!(self.healthcheck())
+	}
+}
+
+#[tokio::test]
+async fn unavailable() -> Result<()> {
+	let unavailable = GlobalApi::builder()
+		.base_url("http://127.0.0.1:1")
+		.health_url("http://127.0.0.1:1")
+		.retry(RetryPolicy::none())
+		.build()?;
+
+	let failover = Failover::new([unavailable, crate::GLOBAL_API.clone()]);
+	assert_eq!(failover.is_degraded(0), Some(false));
+
+	failover.check_health().await;
+	assert_eq!(failover.is_degraded(0), Some(true));
+	assert_eq!(failover.is_degraded(1), Some(false));
+
+	let map = failover.get_map(992_u16.into()).await?;
+	assert_eq!(map.name, "kz_lionharder");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn degraded() -> Result<()> {
+	let mut fixtures = Fixtures {
+		health: Health { successful: 3, fast: 3 },
+		..Default::default()
+	};
+
+	for map in &mut fixtures.maps {
+		map.name = format!("{}_degraded", map.name);
+	}
+
+	let degraded = MockServer::start_with(fixtures)?;
+	let healthy = MockServer::start()?;
+	let failover = Failover::new([degraded.client(), healthy.client()]);
+
+	// Nothing is degraded before the first health check.
+	let map = failover.get_map(992_u16.into()).await?;
+	assert_eq!(map.name, "kz_lionharder_degraded");
+
+	failover.check_health().await;
+	assert_eq!(failover.is_degraded(0), Some(true));
+	assert_eq!(failover.is_degraded(1), Some(false));
+
+	let map = failover.get_map(992_u16.into()).await?;
+	assert_eq!(map.name, "kz_lionharder");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn errors() -> Result<()> {
+	let mock_server = MockServer::start()?;
+	let failover =
+		Failover::new([TestBackend::GlobalApi(mock_server.client()), TestBackend::Unsupported]);
+
+	// A healthy backend not finding anything is the answer.
+	let error = failover
+		.get_wr("kz_lionharder".into(), 9, Mode::SimpleKZ, Runtype::Pro)
+		.await
+		.unwrap_err();
+
+	assert!(error.is_not_found(), "{error:?}");
+
+	let failover = Failover::new([
+		TestBackend::GlobalApi(crate::backend::unavailable()),
+		TestBackend::Unsupported,
+	]);
+
+	failover.check_health().await;
+	assert_eq!(failover.is_degraded(0), Some(true));
+	assert_eq!(failover.is_degraded(1), Some(true));
+
+	// A backend not supporting an operation does not hide why the others failed.
+	let error = failover
+		.get_wr("kz_lionharder".into(), 0, Mode::SimpleKZ, Runtype::Pro)
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, Error::Connection { .. }), "{error:?}");
+
+	Ok(())
+}
@@ -1,7 +1,7 @@
-use {color_eyre::Result, gokz_rs::global_api};
+use color_eyre::Result;
 
 #[tokio::test]
 async fn healthcheck() -> Result<()> {
-	global_api::healthcheck(&crate::GOKZ_CLIENT).await?;
+	crate::GLOBAL_API.healthcheck().await?;
 	Ok(())
 }
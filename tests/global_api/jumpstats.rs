@@ -0,0 +1,89 @@
+use {
+	color_eyre::Result,
+	gokz_rs::{global_api, JumpType, SteamID},
+};
+
+#[tokio::test]
+async fn get_jumpstats() -> Result<()> {
+	let alphakeks = SteamID::try_from(76561198282622073_u64)?;
+	let params = global_api::jumpstats::Params {
+		steam_id: Some(alphakeks),
+		jump_type: Some(JumpType::LongJump),
+		limit: Some(10),
+		..Default::default()
+	};
+
+	let jumpstats = crate::GLOBAL_API.get_jumpstats_with(&params).await?;
+	assert!(!jumpstats.is_empty());
+
+	for jumpstat in jumpstats {
+		assert_eq!(jumpstat.steam_id, alphakeks);
+		assert_eq!(jumpstat.jump_type, JumpType::LongJump);
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn get_top_jumpstats() -> Result<()> {
+	let jumpstats = crate::GLOBAL_API.get_top_jumpstats(JumpType::LongJump).await?;
+
+	assert!(jumpstats
+		.windows(2)
+		.all(|jumps| jumps[0].distance >= jumps[1].distance));
+
+	assert!(jumpstats.iter().all(|jumpstat| !jumpstat.is_crouch_bind));
+
+	let mut players = jumpstats
+		.iter()
+		.map(|jumpstat| jumpstat.steam_id)
+		.collect::<Vec<_>>();
+
+	players.sort_unstable_by_key(|steam_id| steam_id.as_id64());
+	players.dedup();
+	assert_eq!(players.len(), jumpstats.len());
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn create_jumpstat() -> Result<()> {
+	use gokz_rs::{
+		global_api::{jumpstats::NewJumpstat, mock::MockServer, GlobalApi},
+		Error,
+	};
+
+	let mock_server = MockServer::start()?;
+	let new_jumpstat = NewJumpstat {
+		steam_id: SteamID::try_from(76561198282622073_u64)?,
+		jump_type: JumpType::LongJump,
+		distance: 271.5,
+		tickrate: 128,
+		msl_count: 0,
+		strafe_count: 6,
+		is_crouch_bind: false,
+		is_forward_bind: false,
+		is_crouch_boost: false,
+		json_jump_info: String::from("{}"),
+	};
+
+	let error = mock_server
+		.client()
+		.create_jumpstat(&new_jumpstat)
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, Error::Unauthorized { .. }), "{error:?}");
+
+	let global_api = GlobalApi::builder()
+		.base_url(mock_server.base_url())
+		.api_key("secret")
+		.build()?;
+
+	let jumpstat = global_api.create_jumpstat(&new_jumpstat).await?;
+	assert_eq!(jumpstat.steam_id, new_jumpstat.steam_id);
+	assert_eq!(jumpstat.player_name, "AlphaKeks");
+	assert_eq!(jumpstat.jump_type, JumpType::LongJump);
+	assert_eq!(jumpstat.distance, 271.5);
+	Ok(())
+}
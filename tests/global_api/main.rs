@@ -1,8 +1,34 @@
 #![cfg(feature = "global-api")]
 
-use {gokz_rs::http::Client, lazy_regex::Lazy};
+use {gokz_rs::global_api::GlobalApi, lazy_regex::Lazy};
 
-static GOKZ_CLIENT: Lazy<Client> = Lazy::new(Client::new);
+/// Local mock server used when the `mock` feature is enabled and `GLOBAL_API_URL` is not set.
+#[cfg(feature = "mock")]
+static MOCK_SERVER: Lazy<gokz_rs::global_api::mock::MockServer> = Lazy::new(|| {
+	gokz_rs::global_api::mock::MockServer::start().expect("Failed to start mock server")
+});
+
+/// Set `GLOBAL_API_URL` and `GLOBAL_API_HEALTH_URL` to run the tests against a different server.
+static GLOBAL_API: Lazy<GlobalApi> = Lazy::new(|| {
+	#[cfg(feature = "mock")]
+	if std::env::var("GLOBAL_API_URL").is_err() {
+		return MOCK_SERVER.client();
+	}
+
+	let mut global_api = GlobalApi::builder();
+
+	if let Ok(base_url) = std::env::var("GLOBAL_API_URL") {
+		global_api = global_api.base_url(base_url);
+	}
+
+	if let Ok(health_url) = std::env::var("GLOBAL_API_HEALTH_URL") {
+		global_api = global_api.health_url(health_url);
+	}
+
+	global_api
+		.build()
+		.expect("Failed to build GlobalAPI client")
+});
 
 pub mod health;
 pub mod bans;
@@ -11,6 +37,11 @@ pub mod players;
 pub mod record_filters;
 pub mod servers;
 pub mod records;
+pub mod completions;
+pub mod jumpstats;
+pub mod profile;
+pub mod backend;
+pub mod failover;
 
 #[ctor::ctor]
 fn setup() {
@@ -1,8 +1,48 @@
-use {color_eyre::Result, gokz_rs::global_api};
+use {
+	color_eyre::Result,
+	futures::TryStreamExt,
+	gokz_rs::{
+		global_api,
+		http::{Pagination, ResponseCache},
+	},
+};
 
 #[tokio::test]
 async fn get_maps() -> Result<()> {
-	let maps = global_api::get_maps(true, &crate::GOKZ_CLIENT).await?;
+	let maps = crate::GLOBAL_API.get_maps(true).await?;
 	assert!(!maps.is_empty());
 	Ok(())
 }
+
+#[tokio::test]
+async fn stream_maps() -> Result<()> {
+	let params = global_api::maps::Params { is_validated: Some(true), ..Default::default() };
+
+	let maps = crate::GLOBAL_API
+		.stream_maps_with(&params, Pagination::new().page_size(2))
+		.try_collect::<Vec<_>>()
+		.await?;
+
+	assert_eq!(maps, crate::GLOBAL_API.get_maps(true).await?);
+
+	let maps = crate::GLOBAL_API
+		.stream_maps_with(&params, Pagination::new().page_size(2).max_items(3))
+		.try_collect::<Vec<_>>()
+		.await?;
+
+	assert_eq!(maps.len(), 3);
+	Ok(())
+}
+
+#[tokio::test]
+async fn get_maps_cached() -> Result<()> {
+	let global_api = global_api::GlobalApi::builder()
+		.base_url(crate::GLOBAL_API.base_url())
+		.cache(ResponseCache::in_memory(16))
+		.build()?;
+
+	let maps = global_api.get_maps(true).await?;
+	assert_eq!(maps, global_api.get_maps(true).await?);
+	assert_eq!(maps, crate::GLOBAL_API.get_maps(true).await?);
+	Ok(())
+}
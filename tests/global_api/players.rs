@@ -1,12 +1,12 @@
 use {
 	color_eyre::{eyre::Context, Result},
-	gokz_rs::{global_api, global_api::Player, SteamID},
+	gokz_rs::{global_api::Player, SteamID},
 };
 
 #[tokio::test]
 async fn get_players() -> Result<()> {
 	let steam_id = SteamID::try_from(76561198282622073_u64)?;
-	let player = global_api::get_player(steam_id, &crate::GOKZ_CLIENT).await?;
+	let player = crate::GLOBAL_API.get_player(steam_id).await?;
 
 	assert_eq!(player.name, "AlphaKeks");
 	assert_eq!(player.steam_id.to_string(), "STEAM_1:1:161178172");
@@ -28,7 +28,7 @@ async fn get_player_avatar() -> Result<()> {
 		.context("This test requires the `STEAM_WEB_API_KEY` environment variable to be set")?;
 
 	player
-		.avatar_url(&api_key, &crate::GOKZ_CLIENT)
+		.avatar_url(&api_key, crate::GLOBAL_API.client())
 		.await?;
 
 	Ok(())
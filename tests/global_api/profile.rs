@@ -0,0 +1,64 @@
+use {
+	color_eyre::Result,
+	gokz_rs::{
+		profile::{Backends, PlayerProfile},
+		Mode, Runtype, SteamID,
+	},
+};
+
+// `Backends` only has other fields if their features are enabled.
+#[allow(clippy::needless_update)]
+#[tokio::test]
+async fn fetch_profile() -> Result<()> {
+	let steam_id = SteamID::try_from(76561198282622073_u64)?;
+	let backends = Backends { global_api: Some(&crate::GLOBAL_API), ..Default::default() };
+	let profile = PlayerProfile::fetch(steam_id, backends).await?;
+
+	assert_eq!(profile.name, "AlphaKeks");
+	assert_eq!(profile.failures, []);
+	assert_eq!(profile.stats.len(), 6);
+
+	let stats = profile.stats(Mode::SimpleKZ, Runtype::Pro);
+	assert!(stats.completions > 0);
+	assert!(stats.completions_by_tier.iter().sum::<u32>() <= stats.completions);
+	assert!(stats.world_records.is_some());
+	assert!(profile.rank(Mode::SimpleKZ).is_some());
+
+	assert!(!profile.latest_records.is_empty());
+	assert!(profile
+		.latest_records
+		.windows(2)
+		.all(|records| records[0].created_on >= records[1].created_on));
+
+	Ok(())
+}
+
+#[allow(clippy::needless_update)]
+#[tokio::test]
+async fn fetch_missing_profile() -> Result<()> {
+	let steam_id = SteamID::try_from(76561197960265729_u64)?;
+	let backends = Backends { global_api: Some(&crate::GLOBAL_API), ..Default::default() };
+
+	assert!(PlayerProfile::fetch(steam_id, backends).await.is_err());
+	assert!(PlayerProfile::fetch(steam_id, Backends::default()).await.is_err());
+
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[allow(clippy::needless_update)]
+#[tokio::test]
+async fn fetch_profile_world_records() -> Result<()> {
+	let mock_server = gokz_rs::global_api::mock::MockServer::start()?;
+	let global_api = mock_server.client();
+	let steam_id = SteamID::try_from(76561197960365728_u64)?;
+	let backends = Backends { global_api: Some(&global_api), ..Default::default() };
+	let profile = PlayerProfile::fetch(steam_id, backends).await?;
+
+	for stats in &profile.stats {
+		let expected = u32::from(stats.mode == Mode::SimpleKZ);
+		assert_eq!(stats.world_records, Some(expected), "{stats:?}");
+	}
+
+	Ok(())
+}
@@ -63,7 +63,9 @@ async fn get_filters() -> Result<()> {
 		..Default::default()
 	};
 
-	let result = global_api::get_filters_with(&params, &crate::GOKZ_CLIENT).await?;
+	let result = crate::GLOBAL_API
+		.get_filters_with(&params)
+		.await?;
 
 	assert_eq!(result, expected);
 
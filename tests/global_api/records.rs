@@ -1,16 +1,13 @@
 use {
 	color_eyre::Result,
-	gokz_rs::{
-		global_api::{self, Record},
-		Mode,
-	},
+	gokz_rs::{global_api::Record, Mode},
 	serde_json::json,
 };
 
 #[tokio::test]
 async fn get_record() -> Result<()> {
 	let record_id = 14202658;
-	let record = global_api::get_record(record_id, &crate::GOKZ_CLIENT).await?;
+	let record = crate::GLOBAL_API.get_record(record_id).await?;
 	let expected = json!({
 		"id": 14202658,
 		"steamid64": "76561198282622073",
@@ -39,10 +36,214 @@ async fn get_record() -> Result<()> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn get_missing_record() -> Result<()> {
+	let error = crate::GLOBAL_API
+		.get_record(1)
+		.await
+		.unwrap_err();
+
+	assert!(error.is_not_found(), "{error:?}");
+	assert!(!error.is_retryable());
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn submit_record() -> Result<()> {
+	use gokz_rs::{
+		global_api::{mock::MockServer, records::NewRecord, GlobalApi},
+		Error, SteamID,
+	};
+
+	let mock_server = MockServer::start()?;
+	let global_api = GlobalApi::builder()
+		.base_url(mock_server.base_url())
+		.api_key("secret")
+		.build()?;
+
+	let mut record = NewRecord {
+		steam_id: SteamID::try_from(76561198282622073_u64)?,
+		map_id: 992,
+		stage: 0,
+		mode: Mode::SimpleKZ,
+		tickrate: 128,
+		time: 1337.69,
+		teleports: 0,
+		server_id: 1561,
+	};
+
+	let submission = global_api.submit_record(&record).await?;
+	assert_eq!(submission.record_id, 14202659);
+	assert_eq!(submission.replay_id, 1003);
+
+	record.tickrate = 100;
+	let error = global_api.submit_record(&record).await.unwrap_err();
+	assert!(matches!(error, Error::InvalidTickrate(_)), "{error:?}");
+
+	record.tickrate = 128;
+	record.time = -1.0;
+	let error = global_api.submit_record(&record).await.unwrap_err();
+	assert!(matches!(error, Error::InvalidTime(_)), "{error:?}");
+
+	record.time = 1337.69;
+	record.map_id = 0;
+	let error = global_api.submit_record(&record).await.unwrap_err();
+	assert!(matches!(error, Error::InvalidMapID(_)), "{error:?}");
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn validate_record() -> Result<()> {
+	use gokz_rs::{
+		global_api::{filters, records::NewRecord},
+		Error, SteamID, Tier,
+	};
+
+	let map = crate::GLOBAL_API.get_map("kz_lionharder").await?;
+	let params = filters::Params { map_ids: Some(vec![map.id]), ..Default::default() };
+	let filters = crate::GLOBAL_API.get_filters_with(&params).await?;
+
+	let mut record = NewRecord {
+		steam_id: SteamID::try_from(76561198282622073_u64)?,
+		map_id: map.id,
+		stage: 1,
+		mode: Mode::SimpleKZ,
+		tickrate: 128,
+		time: 69.42,
+		teleports: 3,
+		server_id: 1561,
+	};
+
+	assert_eq!(record.validate_for(&map, &filters)?, Tier::Death);
+
+	record.stage = 2;
+	let error = record.validate_for(&map, &filters).unwrap_err();
+	assert!(matches!(error, Error::InvalidStage(_)), "{error:?}");
+
+	record.stage = 0;
+	record.mode = Mode::Vanilla;
+	let error = record.validate_for(&map, &filters).unwrap_err();
+	assert!(matches!(error, Error::NotRanked(_)), "{error:?}");
+
+	record.mode = Mode::SimpleKZ;
+	record.map_id = 198;
+	let error = record.validate_for(&map, &filters).unwrap_err();
+	assert!(matches!(error, Error::InvalidMapID(_)), "{error:?}");
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn upload_replay() -> Result<()> {
+	use gokz_rs::{
+		global_api::{mock::MockServer, GlobalApi},
+		Error,
+	};
+
+	let mock_server = MockServer::start()?;
+	let replay = mock_server.client().get_replay_file(1001).await?;
+
+	let error = mock_server
+		.client()
+		.upload_replay(14202658, replay.clone())
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, Error::Unauthorized { .. }), "{error:?}");
+
+	let global_api = GlobalApi::builder()
+		.base_url(mock_server.base_url())
+		.api_key("secret")
+		.build()?;
+
+	global_api
+		.upload_replay(14202658, replay.clone())
+		.await?;
+
+	let error = global_api
+		.upload_replay(14202658, b"not a replay".to_vec())
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, Error::Http { .. }), "{error:?}");
+
+	let error = global_api
+		.upload_replay(1, replay)
+		.await
+		.unwrap_err();
+
+	assert!(error.is_not_found(), "{error:?}");
+	Ok(())
+}
+
+/// The bundled replay fixtures are synthetic, so this checks real replays from the live GlobalAPI.
+///
+/// `REPLAY_IDS` may contain a comma separated list of replay ids to check. Otherwise the oldest
+/// and newest replays on the `kz_lionharder` leaderboard are used.
+#[tokio::test]
+#[ignore = "requires network access to the GlobalAPI"]
+async fn real_replays_round_trip() -> Result<()> {
+	use gokz_rs::{global_api::GlobalApi, replay::Replay, Runtype};
+
+	let global_api = GlobalApi::builder().build()?;
+	let replay_ids = match std::env::var("REPLAY_IDS") {
+		Ok(ids) => ids
+			.split(',')
+			.map(|id| id.trim().parse::<u32>())
+			.collect::<std::result::Result<Vec<_>, _>>()?,
+		Err(_) => {
+			let replay_ids = global_api
+				.get_maptop("kz_lionharder", 0, Mode::SimpleKZ, Runtype::Pro, 100)
+				.await?
+				.into_iter()
+				.map(|record| record.replay_id)
+				.filter(|&replay_id| replay_id != 0)
+				.collect::<Vec<_>>();
+
+			let oldest = replay_ids.iter().min().copied();
+			let newest = replay_ids.iter().max().copied();
+			oldest.into_iter().chain(newest).collect()
+		}
+	};
+
+	assert!(!replay_ids.is_empty());
+
+	for replay_id in replay_ids {
+		let bytes = global_api.get_replay_file(replay_id).await?;
+		let replay = Replay::parse(&bytes)?;
+
+		assert_eq!(replay.to_bytes()?, bytes, "replay {replay_id} did not round-trip");
+	}
+
+	Ok(())
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn get_replay() -> Result<()> {
+	use gokz_rs::replay::ReplayKind;
+
+	let replay = crate::GLOBAL_API.get_replay(1001).await?;
+
+	assert_eq!(replay.map_name, "kz_lionharder");
+	assert_eq!(replay.mode, Mode::SimpleKZ);
+	assert_eq!(replay.player_alias, "Mock Runner");
+	assert!(matches!(
+		replay.kind,
+		ReplayKind::Run { teleports: 97, time, .. } if (time - 433.102).abs() < 0.001
+	));
+
+	let error = crate::GLOBAL_API.get_replay(1).await.unwrap_err();
+	assert!(error.is_not_found(), "{error:?}");
+	Ok(())
+}
+
 #[tokio::test]
 async fn get_place() -> Result<()> {
 	let record_id = 14202658;
-	let place = global_api::get_place(record_id, &crate::GOKZ_CLIENT).await?;
+	let place = crate::GLOBAL_API.get_place(record_id).await?;
 
 	assert!(place > 0);
 
@@ -51,22 +252,18 @@ async fn get_place() -> Result<()> {
 
 #[tokio::test]
 async fn get_wr() -> Result<()> {
-	global_api::get_wr("kz_lionharder", 0, Mode::SimpleKZ, true, &crate::GOKZ_CLIENT).await?;
+	crate::GLOBAL_API
+		.get_wr("kz_lionharder", 0, Mode::SimpleKZ, true)
+		.await?;
 
 	Ok(())
 }
 
 #[tokio::test]
 async fn get_pb() -> Result<()> {
-	let record = global_api::get_pb(
-		"AlphaKeks",
-		"kz_lionharder",
-		0,
-		Mode::SimpleKZ,
-		true,
-		&crate::GOKZ_CLIENT,
-	)
-	.await?;
+	let record = crate::GLOBAL_API
+		.get_pb("AlphaKeks", "kz_lionharder", 0, Mode::SimpleKZ, true)
+		.await?;
 
 	let expected = json!({
 		"id": 13469730,
@@ -98,15 +295,9 @@ async fn get_pb() -> Result<()> {
 
 #[tokio::test]
 async fn get_maptop() -> Result<()> {
-	let maptop = global_api::get_maptop(
-		"kz_beginnerblock_go",
-		0,
-		Mode::KZTimer,
-		true,
-		100,
-		&crate::GOKZ_CLIENT,
-	)
-	.await?;
+	let maptop = crate::GLOBAL_API
+		.get_maptop("kz_beginnerblock_go", 0, Mode::KZTimer, true, 100)
+		.await?;
 
 	assert_eq!(maptop.len(), 100);
 	Ok(())
@@ -114,7 +305,69 @@ async fn get_maptop() -> Result<()> {
 
 #[tokio::test]
 async fn get_wr_leaderboard() -> Result<()> {
-	global_api::get_wr_leaderboard(0..=0, Mode::KZTimer, false, 1, &crate::GOKZ_CLIENT).await?;
+	crate::GLOBAL_API
+		.get_wr_leaderboard(0..=0, Mode::KZTimer, false, 1)
+		.await?;
+
+	Ok(())
+}
+
+/// World record leaderboards only count 128 tick records.
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn get_wr_leaderboard_tickrate() -> Result<()> {
+	use gokz_rs::global_api::mock::{Fixtures, MockServer};
+
+	let mut fixtures = Fixtures::default();
+	for record in &mut fixtures.records {
+		record.tickrate = 64;
+	}
+
+	let mock_server = MockServer::start_with(fixtures)?;
+	let error = mock_server
+		.client()
+		.get_wr_leaderboard(0..=0, Mode::KZTimer, false, 1)
+		.await
+		.unwrap_err();
+
+	assert!(error.is_not_found(), "{error:?}");
+	Ok(())
+}
+
+#[tokio::test]
+async fn get_overall_records() -> Result<()> {
+	use {
+		gokz_rs::{global_api::records::Params, Runtype},
+		std::collections::HashMap,
+	};
+
+	let params = Params {
+		map_name: Some(String::from("kz_lionharder")),
+		stage: Some(0),
+		mode: Some(Mode::SimpleKZ),
+		limit: Some(9999),
+		..Default::default()
+	};
+
+	let mut fastest = HashMap::new();
+	for runtype in [Runtype::Pro, Runtype::TP] {
+		let params = Params { runtype: Some(runtype), ..params.clone() };
+
+		for record in crate::GLOBAL_API.get_records_with(&params).await? {
+			let time = fastest.entry(record.steam_id).or_insert(record.time);
+			*time = record.time.min(*time);
+		}
+	}
+
+	let overall = crate::GLOBAL_API
+		.get_records_with(&Params { nub: Some(true), ..params })
+		.await?;
+
+	assert_eq!(overall.len(), fastest.len());
+
+	for record in overall {
+		assert_eq!(record.time, fastest[&record.steam_id], "{record:?}");
+	}
 
 	Ok(())
 }
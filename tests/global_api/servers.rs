@@ -1,13 +1,15 @@
 use {
 	color_eyre::Result,
-	gokz_rs::{global_api, global_api::Server, SteamID},
+	gokz_rs::{global_api::Server, SteamID},
 	serde_json::json,
 };
 
 #[tokio::test]
 async fn get_servers() -> Result<()> {
 	let steam_id = SteamID::try_from(76561198282622073_u64)?;
-	let servers = global_api::get_servers_owned_by(steam_id, &crate::GOKZ_CLIENT).await?;
+	let servers = crate::GLOBAL_API
+		.get_servers_owned_by(steam_id)
+		.await?;
 
 	let expected = json!([
 		{
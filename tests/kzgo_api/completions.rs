@@ -1,10 +1,9 @@
-use {
-	color_eyre::Result,
-	gokz_rs::{kzgo_api, Mode},
-};
+use {color_eyre::Result, gokz_rs::Mode};
 
 #[tokio::test]
 async fn get_completions() -> Result<()> {
-	kzgo_api::get_completions(Mode::SimpleKZ, &crate::GOKZ_CLIENT).await?;
+	crate::KZGO_API
+		.get_completions(Mode::SimpleKZ)
+		.await?;
 	Ok(())
 }
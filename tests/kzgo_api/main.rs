@@ -1,8 +1,30 @@
 #![cfg(feature = "kzgo-api")]
 
-use {gokz_rs::http::Client, lazy_regex::Lazy};
+use {gokz_rs::kzgo_api::KzgoApi, lazy_regex::Lazy};
 
-static GOKZ_CLIENT: Lazy<Client> = Lazy::new(Client::new);
+/// Local mock server used when the `mock` feature is enabled and `KZGO_API_URL` is not set.
+#[cfg(feature = "mock")]
+static MOCK_SERVER: Lazy<gokz_rs::kzgo_api::mock::MockServer> = Lazy::new(|| {
+	gokz_rs::kzgo_api::mock::MockServer::start().expect("Failed to start mock server")
+});
+
+/// Set `KZGO_API_URL` to run the tests against a different server.
+static KZGO_API: Lazy<KzgoApi> = Lazy::new(|| {
+	#[cfg(feature = "mock")]
+	if std::env::var("KZGO_API_URL").is_err() {
+		return MOCK_SERVER.client();
+	}
+
+	let mut kzgo_api = KzgoApi::builder();
+
+	if let Ok(base_url) = std::env::var("KZGO_API_URL") {
+		kzgo_api = kzgo_api.base_url(base_url);
+	}
+
+	kzgo_api
+		.build()
+		.expect("Failed to build KZ:GO client")
+});
 
 pub mod maps;
 pub mod servers;
@@ -2,7 +2,7 @@ use {color_eyre::Result, gokz_rs::kzgo_api, serde_json::json};
 
 #[tokio::test]
 async fn get_map() -> Result<()> {
-	let lionharder = kzgo_api::get_map("kz_lionharder", &crate::GOKZ_CLIENT).await?;
+	let lionharder = crate::KZGO_API.get_map("kz_lionharder").await?;
 
 	let expected = json!({
 		"id": 992,
@@ -30,7 +30,7 @@ async fn get_map() -> Result<()> {
 
 #[tokio::test]
 async fn get_maps() -> Result<()> {
-	let maps = kzgo_api::get_maps(&crate::GOKZ_CLIENT).await?;
+	let maps = crate::KZGO_API.get_maps().await?;
 
 	assert!(!maps.is_empty());
 
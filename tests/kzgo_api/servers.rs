@@ -1,7 +1,7 @@
-use {color_eyre::Result, gokz_rs::kzgo_api};
+use color_eyre::Result;
 
 #[tokio::test]
 async fn get_servers() -> Result<()> {
-	kzgo_api::get_servers(&crate::GOKZ_CLIENT).await?;
+	crate::KZGO_API.get_servers().await?;
 	Ok(())
 }
@@ -1,10 +1,9 @@
-use {
-	color_eyre::Result,
-	gokz_rs::{kzgo_api, Mode},
-};
+use {color_eyre::Result, gokz_rs::Mode};
 
 #[tokio::test]
 async fn get_world_records() -> Result<()> {
-	kzgo_api::get_world_records(Mode::KZTimer, &crate::GOKZ_CLIENT).await?;
+	crate::KZGO_API
+		.get_world_records(Mode::KZTimer)
+		.await?;
 	Ok(())
 }